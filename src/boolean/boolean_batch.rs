@@ -0,0 +1,210 @@
+use argmin::core::ArgminFloat;
+use nalgebra::{Const, Point2};
+
+use crate::{
+    misc::{EndPoints, FloatingPoint},
+    prelude::{BoundingBox, Contains, CurveIntersectionSolverOptions, Intersects},
+    region::Region,
+};
+
+use super::{degeneracies::close_gaps, Boolean};
+
+impl<T: FloatingPoint + ArgminFloat> Region<T> {
+    /// Union all the regions at once
+    /// The regions are merged in a divide-and-conquer manner to balance the complexity of the operands.
+    /// The merged regions are kept sorted by the minimum x of their bounding boxes,
+    /// so that only the regions whose bounding boxes overlap are visited by the sweep.
+    /// Returns the disjoint regions covering all the given regions.
+    /// # Example
+    /// ```
+    /// use curvo::prelude::*;
+    /// use nalgebra::Point2;
+    /// let square = |x: f64, y: f64| -> Region<f64> {
+    ///     NurbsCurve2D::polyline(&[
+    ///         Point2::new(x, y),
+    ///         Point2::new(x + 1., y),
+    ///         Point2::new(x + 1., y + 1.),
+    ///         Point2::new(x, y + 1.),
+    ///         Point2::new(x, y),
+    ///     ], true).into()
+    /// };
+    ///
+    /// // a row of overlapping squares & an isolated square
+    /// let mut regions = (0..8).map(|i| square(i as f64 * 0.5, (i % 2) as f64 * 0.25)).collect::<Vec<_>>();
+    /// regions.push(square(10., 10.));
+    ///
+    /// let union = Region::union_all(&regions, None).unwrap();
+    /// assert_eq!(union.len(), 2);
+    /// ```
+    pub fn union_all(
+        regions: &[Region<T>],
+        option: Option<CurveIntersectionSolverOptions<T>>,
+    ) -> anyhow::Result<Vec<Region<T>>> {
+        Ok(union_sorted(regions, option)?
+            .into_iter()
+            .map(|(_, region)| region)
+            .collect())
+    }
+
+    /// Subtract many regions from the region at once
+    /// The subtracted regions are united in advance by `union_all`,
+    /// and only the regions whose bounding boxes overlap with the region are operated.
+    /// # Example
+    /// ```
+    /// use curvo::prelude::*;
+    /// use nalgebra::{Point2, Vector2};
+    /// let rectangle = NurbsCurve2D::polyline(&[
+    ///     Point2::new(0., 0.),
+    ///     Point2::new(10., 0.),
+    ///     Point2::new(10., 2.),
+    ///     Point2::new(0., 2.),
+    ///     Point2::new(0., 0.),
+    /// ], true);
+    /// let subject: Region<f64> = rectangle.into();
+    ///
+    /// // punch holes in a row
+    /// let holes = (0..4).map(|i| {
+    ///     let center = Point2::new(1.5 + i as f64 * 2.0, 1.);
+    ///     NurbsCurve2D::try_circle(&center, &Vector2::x(), &Vector2::y(), 0.5).unwrap().into()
+    /// }).collect::<Vec<Region<f64>>>();
+    ///
+    /// let regions = subject.difference_many(&holes, None).unwrap();
+    /// assert_eq!(regions.len(), 1);
+    /// assert_eq!(regions[0].interiors().len(), 4);
+    /// ```
+    pub fn difference_many(
+        &self,
+        others: &[Region<T>],
+        option: Option<CurveIntersectionSolverOptions<T>>,
+    ) -> anyhow::Result<Vec<Region<T>>> {
+        let bb: BoundingBox<T, Const<2>> = self.into();
+        let others = others
+            .iter()
+            .filter(|other| bb.intersects(&(*other).into(), None))
+            .cloned()
+            .collect::<Vec<_>>();
+
+        let mut regions = vec![self.clone()];
+        for other in Self::union_all(&others, option.clone())? {
+            let obb: BoundingBox<T, Const<2>> = (&other).into();
+            let mut next = vec![];
+            for region in regions.into_iter() {
+                let rbb: BoundingBox<T, Const<2>> = (&region).into();
+                if rbb.intersects(&obb, None) {
+                    let clip = region.difference(&other, option.clone())?;
+                    next.extend(clip.into_regions().into_iter().map(close_gaps));
+                } else {
+                    next.push(region);
+                }
+            }
+            regions = next;
+        }
+        Ok(regions)
+    }
+}
+
+/// A region paired with its bounding box
+type BoxedRegion<T> = (BoundingBox<T, Const<2>>, Region<T>);
+
+/// Union the regions into the disjoint regions sorted by the minimum x of their bounding boxes
+fn union_sorted<T: FloatingPoint + ArgminFloat>(
+    regions: &[Region<T>],
+    option: Option<CurveIntersectionSolverOptions<T>>,
+) -> anyhow::Result<Vec<BoxedRegion<T>>> {
+    match regions.len() {
+        0 => Ok(vec![]),
+        1 => Ok(vec![((&regions[0]).into(), regions[0].clone())]),
+        n => {
+            let (left, right) = regions.split_at(n / 2);
+            let mut merged = union_sorted(left, option.clone())?;
+            for (_, region) in union_sorted(right, option.clone())? {
+                merge_region(&mut merged, region, option.clone())?;
+            }
+            Ok(merged)
+        }
+    }
+}
+
+/// Merge the region into the disjoint regions sorted by the minimum x of their bounding boxes
+/// The region absorbs the overlapping regions one by one until no overlapping region remains.
+/// Only the regions starting before the end of the region in x are visited,
+/// and the others are skipped by their bounding boxes before the exact overlap test.
+/// When a union results in several regions, each of them is merged in turn.
+fn merge_region<T: FloatingPoint + ArgminFloat>(
+    regions: &mut Vec<BoxedRegion<T>>,
+    region: Region<T>,
+    option: Option<CurveIntersectionSolverOptions<T>>,
+) -> anyhow::Result<()> {
+    let mut pending = vec![region];
+    while let Some(mut current) = pending.pop() {
+        let mut bb: BoundingBox<T, Const<2>> = (&current).into();
+        'merge: loop {
+            let end = regions.partition_point(|(other, _)| other.min().x <= bb.max().x);
+            for i in 0..end {
+                let (obb, other) = &regions[i];
+                if obb.max().x < bb.min().x
+                    || !bb.intersects(obb, None)
+                    || !overlaps(&current, other, option.clone())?
+                {
+                    continue;
+                }
+
+                let (_, other) = regions.remove(i);
+                let mut united = current
+                    .union(&other, option.clone())?
+                    .into_regions()
+                    .into_iter()
+                    .map(close_gaps);
+                current = united
+                    .next()
+                    .ok_or(anyhow::anyhow!("No region found in union"))?;
+                pending.extend(united);
+                bb = (&current).into();
+                continue 'merge;
+            }
+            break;
+        }
+        let index = regions.partition_point(|(other, _)| other.min().x < bb.min().x);
+        regions.insert(index, (bb, current));
+    }
+    Ok(())
+}
+
+/// Check if the areas of the regions overlap or not
+/// The regions overlap if their boundaries intersect, or if a point of one region lies in the area of the other,
+/// where a point inside a hole is not in the area.
+fn overlaps<T: FloatingPoint + ArgminFloat>(
+    a: &Region<T>,
+    b: &Region<T>,
+    option: Option<CurveIntersectionSolverOptions<T>>,
+) -> anyhow::Result<bool> {
+    for ca in std::iter::once(a.exterior()).chain(a.interiors()) {
+        for cb in std::iter::once(b.exterior()).chain(b.interiors()) {
+            if !ca.find_intersection(cb, option.clone())?.is_empty() {
+                return Ok(true);
+            }
+        }
+    }
+
+    Ok(
+        area_contains(a, &b.exterior().first_point(), option.clone())?
+            || area_contains(b, &a.exterior().first_point(), option)?,
+    )
+}
+
+/// Check if the point lies inside the exterior of the region and outside its holes
+fn area_contains<T: FloatingPoint + ArgminFloat>(
+    region: &Region<T>,
+    point: &Point2<T>,
+    option: Option<CurveIntersectionSolverOptions<T>>,
+) -> anyhow::Result<bool> {
+    if !region.exterior().contains(point, option.clone())? {
+        return Ok(false);
+    }
+    for interior in region.interiors() {
+        if interior.contains(point, option.clone())? {
+            return Ok(false);
+        }
+    }
+    Ok(true)
+}
@@ -7,7 +7,7 @@ use crate::{
     region::CompoundCurve,
 };
 
-use super::clip::{clip, Clip};
+use super::clip::{clip, clip_symmetric_difference, Clip};
 use super::operation::BooleanOperation;
 use super::Boolean;
 
@@ -23,7 +23,12 @@ impl<'a, T: FloatingPoint + ArgminFloat> Boolean<&'a NurbsCurve<T, U3>> for Comp
         option: Self::Option,
     ) -> Self::Output {
         let intersections = self.find_intersection(other, option.clone())?;
-        clip(self, other, operation, option, intersections)
+        match operation {
+            BooleanOperation::SymmetricDifference => {
+                clip_symmetric_difference(self, other, option, intersections)
+            }
+            _ => clip(self, other, operation, option, intersections),
+        }
     }
 }
 
@@ -41,6 +46,11 @@ impl<'a, T: FloatingPoint + ArgminFloat> Boolean<&'a CompoundCurve<T, U3>>
         option: Self::Option,
     ) -> Self::Output {
         let intersections = self.find_intersection(other, option.clone())?;
-        clip(self, other, operation, option, intersections)
+        match operation {
+            BooleanOperation::SymmetricDifference => {
+                clip_symmetric_difference(self, other, option, intersections)
+            }
+            _ => clip(self, other, operation, option, intersections),
+        }
     }
 }
@@ -1,6 +1,6 @@
 use std::cmp::Ordering;
 
-use super::clip::{clip, Clip};
+use super::clip::{clip, clip_symmetric_difference, Clip};
 use super::operation::BooleanOperation;
 use super::Boolean;
 use argmin::core::ArgminFloat;
@@ -27,7 +27,12 @@ impl<'a, T: FloatingPoint + ArgminFloat> Boolean<&'a NurbsCurve<T, U3>> for Nurb
             .into_iter()
             .map(|it| CompoundCurveIntersection::new(self, other, it))
             .collect_vec();
-        clip(self, other, operation, option, intersections)
+        match operation {
+            BooleanOperation::SymmetricDifference => {
+                clip_symmetric_difference(self, other, option, intersections)
+            }
+            _ => clip(self, other, operation, option, intersections),
+        }
     }
 }
 
@@ -52,6 +57,11 @@ impl<'a, T: FloatingPoint + ArgminFloat> Boolean<&'a CompoundCurve<T, U3>> for N
             })
             .collect_vec();
         sorted.iter_mut().for_each(|it| it.swap());
-        clip(self, other, operation, option, sorted)
+        match operation {
+            BooleanOperation::SymmetricDifference => {
+                clip_symmetric_difference(self, other, option, sorted)
+            }
+            _ => clip(self, other, operation, option, sorted),
+        }
     }
 }
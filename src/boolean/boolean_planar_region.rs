@@ -0,0 +1,64 @@
+use argmin::core::ArgminFloat;
+
+use crate::{misc::FloatingPoint, prelude::CurveIntersectionSolverOptions, region::PlanarRegion};
+
+use super::operation::BooleanOperation;
+use super::Boolean;
+
+/// Boolean operation for two planar regions lying on the same plane in 3D space
+impl<'a, T: FloatingPoint + ArgminFloat> Boolean<&'a PlanarRegion<T>> for PlanarRegion<T> {
+    type Output = anyhow::Result<Vec<PlanarRegion<T>>>;
+    type Option = Option<CurveIntersectionSolverOptions<T>>;
+
+    /// The other region is mapped into the local 2D coordinates of this region,
+    /// and the resulting regions share the plane frame with this region.
+    /// Returns an error if the regions are not lying on the same plane.
+    /// # Example
+    /// ```
+    /// use curvo::prelude::*;
+    /// use nalgebra::{Point3, Vector3};
+    /// use approx::assert_relative_eq;
+    ///
+    /// let x = Vector3::x();
+    /// let y = Vector3::new(0., 1., 1.).normalize();
+    /// let square = |center: Point3<f64>| {
+    ///     let curve = NurbsCurve3D::polyline(&[
+    ///         center - x - y,
+    ///         center + x - y,
+    ///         center + x + y,
+    ///         center - x + y,
+    ///         center - x - y,
+    ///     ], true);
+    ///     PlanarRegion::try_new(curve.into(), vec![]).unwrap()
+    /// };
+    /// let a = square(Point3::origin());
+    /// let b = square(Point3::origin() + x + y);
+    ///
+    /// let regions = a.intersection(&b, None).unwrap();
+    /// assert_eq!(regions.len(), 1);
+    /// let exterior = regions[0].exterior();
+    /// let (start, end) = exterior.knots_domain();
+    /// let plane = a.plane();
+    /// for t in [start, (start + end) * 0.5, end] {
+    ///     assert_relative_eq!(plane.signed_distance(&exterior.point_at(t)), 0., epsilon = 1e-8);
+    /// }
+    /// ```
+    fn boolean(
+        &self,
+        operation: BooleanOperation,
+        other: &'a PlanarRegion<T>,
+        option: Self::Option,
+    ) -> Self::Output {
+        let tolerance = option
+            .as_ref()
+            .map(|o| o.minimum_distance)
+            .unwrap_or(T::from_f64(1e-5).unwrap());
+        let region = self.try_map_region(other, tolerance)?;
+        let clip = self.region().boolean(operation, &region, option)?;
+        Ok(clip
+            .into_regions()
+            .into_iter()
+            .map(|region| self.with_region(region))
+            .collect())
+    }
+}
@@ -10,6 +10,7 @@ use crate::region::Region;
 use crate::{curve::NurbsCurve, misc::FloatingPoint, prelude::CurveIntersectionSolverOptions};
 
 use super::clip::Clip;
+use super::degeneracies::close_gaps;
 use super::operation::BooleanOperation;
 use super::Boolean;
 
@@ -41,6 +42,10 @@ impl<'a, T: FloatingPoint + ArgminFloat> Boolean<&'a CompoundCurve<T, U3>> for R
         option: Self::Option,
     ) -> Self::Output {
         match operation {
+            BooleanOperation::SymmetricDifference => {
+                let other: Region<T> = other.clone().into();
+                self.boolean(operation, &other, option)
+            }
             BooleanOperation::Union => {
                 let exterior =
                     self.exterior()
@@ -157,19 +162,57 @@ impl<'a, T: FloatingPoint + ArgminFloat> Boolean<&'a Region<T>> for Region<T> {
         other: &'a Region<T>,
         option: Self::Option,
     ) -> Self::Output {
+        if operation == BooleanOperation::SymmetricDifference {
+            // (self - other) + (other - self)
+            let a = self.boolean(BooleanOperation::Difference, other, option.clone())?;
+            let b = other.boolean(BooleanOperation::Difference, self, option)?;
+            return Ok(Clip::new(
+                [a.into_regions(), b.into_regions()].concat(),
+                Default::default(),
+            ));
+        }
+
         let clip = self.boolean(operation, other.exterior(), option.clone())?;
         let mut regions = clip.into_regions();
 
         for interior in other.interiors().iter() {
             match operation {
-                BooleanOperation::Union | BooleanOperation::Intersection => {
+                BooleanOperation::Union => {
+                    // the hole of the other remains only where this region does not cover it
+                    let holes = Region::from(interior.clone())
+                        .boolean(BooleanOperation::Difference, self, option.clone())?
+                        .into_regions()
+                        .into_iter()
+                        .map(close_gaps)
+                        .collect_vec();
+                    for hole in holes.iter() {
+                        let clips = regions
+                            .into_iter()
+                            .map(|r| {
+                                close_gaps(r).boolean(
+                                    BooleanOperation::Difference,
+                                    hole,
+                                    option.clone(),
+                                )
+                            })
+                            .collect::<anyhow::Result<Vec<_>>>()?;
+                        regions = clips.into_iter().flat_map(|c| c.into_regions()).collect();
+                    }
+                }
+                BooleanOperation::Intersection => {
                     let clips = regions
                         .iter()
                         .map(|r| r.boolean(BooleanOperation::Difference, interior, option.clone()))
                         .collect::<anyhow::Result<Vec<_>>>()?;
                     regions = clips.into_iter().flat_map(|c| c.into_regions()).collect();
                 }
-                BooleanOperation::Difference => {}
+                BooleanOperation::Difference => {
+                    // the parts of this region inside the holes of the other remain
+                    let clip =
+                        self.boolean(BooleanOperation::Intersection, interior, option.clone())?;
+                    regions.extend(clip.into_regions());
+                }
+                BooleanOperation::SymmetricDifference => {}
             };
         }
 
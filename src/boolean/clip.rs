@@ -83,7 +83,59 @@ impl<T: FloatingPoint> Clip<T> {
     }
 }
 
+/// Boolean operation which can be clipped at once
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ClipOperation {
+    Union,
+    Intersection,
+    Difference,
+}
+
+/// Symmetric difference for two curves
+/// The regions covered by exactly one of the curves are computed as (subject - clip) + (clip - subject),
+/// reusing the intersections for both differences.
+pub fn clip_symmetric_difference<'a, T: FloatingPoint, S, C, O: Clone>(
+    subject: &'a S,
+    clip: &'a C,
+    option: O,
+    intersections: Vec<CompoundCurveIntersection<'a, T, U3>>,
+) -> anyhow::Result<Clip<T>>
+where
+    S: Clone
+        + Contains<C, Option = O>
+        + EndPoints<T, U2>
+        + Into<CompoundCurve<T, U3>>
+        + TrimRange<T, U3>,
+    C: Clone
+        + Contains<S, Option = O>
+        + EndPoints<T, U2>
+        + Into<CompoundCurve<T, U3>>
+        + TrimRange<T, U3>,
+{
+    let swapped = intersections
+        .iter()
+        .cloned()
+        .map(|mut it| {
+            it.swap();
+            it
+        })
+        .collect_vec();
+    let a = self::clip(
+        subject,
+        clip,
+        BooleanOperation::Difference,
+        option.clone(),
+        intersections,
+    )?;
+    let b = self::clip(clip, subject, BooleanOperation::Difference, option, swapped)?;
+    let (regions_a, mut info) = (a.regions, a.info);
+    info.chunks.extend(b.info.chunks);
+    info.spans.extend(b.info.spans);
+    Ok(Clip::new([regions_a, b.regions].concat(), info))
+}
+
 /// Boolean operation for two curves.
+/// The symmetric difference is not supported here, use `clip_symmetric_difference` for it.
 /// Base algorithm reference: Efficient clipping of arbitrary polygons (https://www.inf.usi.ch/hormann/papers/Greiner.1998.ECO.pdf)
 pub fn clip<'a, T: FloatingPoint, S, C, O: Clone>(
     subject: &'a S,
@@ -104,6 +156,15 @@ where
         + Into<CompoundCurve<T, U3>>
         + TrimRange<T, U3>,
 {
+    let operation = match operation {
+        BooleanOperation::Union => ClipOperation::Union,
+        BooleanOperation::Intersection => ClipOperation::Intersection,
+        BooleanOperation::Difference => ClipOperation::Difference,
+        BooleanOperation::SymmetricDifference => anyhow::bail!(
+            "Symmetric difference cannot be clipped at once, use clip_symmetric_difference instead"
+        ),
+    };
+
     let deg = intersections
         .into_iter()
         .map(|it| {
@@ -147,11 +208,11 @@ where
     if indexed.is_empty() {
         let res = match (subject_contains_clip, clip_contains_subject) {
             (true, false) => match operation {
-                BooleanOperation::Union => vec![Region::new(subject.clone().into(), vec![])],
-                BooleanOperation::Intersection => {
+                ClipOperation::Union => vec![Region::new(subject.clone().into(), vec![])],
+                ClipOperation::Intersection => {
                     vec![Region::new(clip.clone().into(), vec![])]
                 }
-                BooleanOperation::Difference => {
+                ClipOperation::Difference => {
                     vec![Region::new(
                         subject.clone().into(),
                         vec![clip.clone().into()],
@@ -159,21 +220,21 @@ where
                 }
             },
             (false, true) => match operation {
-                BooleanOperation::Union => vec![Region::new(clip.clone().into(), vec![])],
-                BooleanOperation::Intersection => {
+                ClipOperation::Union => vec![Region::new(clip.clone().into(), vec![])],
+                ClipOperation::Intersection => {
                     vec![Region::new(subject.clone().into(), vec![])]
                 }
-                BooleanOperation::Difference => {
+                ClipOperation::Difference => {
                     vec![]
                 }
             },
             (false, false) => match operation {
-                BooleanOperation::Union => vec![
+                ClipOperation::Union => vec![
                     Region::new(subject.clone().into(), vec![]),
                     Region::new(clip.clone().into(), vec![]),
                 ],
-                BooleanOperation::Intersection => vec![],
-                BooleanOperation::Difference => {
+                ClipOperation::Intersection => vec![],
+                ClipOperation::Difference => {
                     vec![Region::new(subject.clone().into(), vec![])]
                 }
             },
@@ -251,7 +312,7 @@ where
     });
 
     match operation {
-        BooleanOperation::Union | BooleanOperation::Difference => {
+        ClipOperation::Union | ClipOperation::Difference => {
             // invert a status
             a.iter().for_each(|node| {
                 node.borrow_mut().status_mut().invert();
@@ -260,7 +321,7 @@ where
         _ => {}
     }
 
-    if operation == BooleanOperation::Union {
+    if operation == ClipOperation::Union {
         // invert b status
         b.iter().for_each(|node| {
             node.borrow_mut().status_mut().invert();
@@ -1,9 +1,10 @@
-use nalgebra::{ComplexField, Const};
+use nalgebra::{ComplexField, Const, U3};
 
 use crate::{
     curve::NurbsCurve,
     misc::{FloatingPoint, Line},
     prelude::HasIntersectionParameter,
+    region::{CompoundCurve, Region},
 };
 
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -45,6 +46,34 @@ impl<T: FloatingPoint> Degeneracy<T> {
     }
 }
 
+/// Snap the end points of the adjacent spans in the boundaries of the region
+/// to prevent the small gaps produced by the clipping from accumulating over the successive operations.
+pub(crate) fn close_gaps<T: FloatingPoint>(region: Region<T>) -> Region<T> {
+    let (exterior, interiors) = region.into_tuple();
+    Region::new(
+        close_curve_gaps(exterior),
+        interiors.into_iter().map(close_curve_gaps).collect(),
+    )
+}
+
+/// Snap the end point of each span to the start point of the next span in the closed curve
+pub(crate) fn close_curve_gaps<T: FloatingPoint>(
+    curve: CompoundCurve<T, U3>,
+) -> CompoundCurve<T, U3> {
+    let mut spans = curve.into_spans();
+    let n = spans.len();
+    for i in 0..n {
+        let end = spans[i].control_points().last().map(|p| p.xy() / p.z);
+        let next = (i + 1) % n;
+        if let (Some(end), Some(p)) = (end, spans[next].control_points_iter_mut().next()) {
+            let w = p.z;
+            p.x = end.x * w;
+            p.y = end.y * w;
+        }
+    }
+    CompoundCurve::new_unchecked(spans)
+}
+
 #[cfg(test)]
 mod tests {
     use crate::prelude::*;
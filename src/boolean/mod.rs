@@ -1,10 +1,12 @@
 use operation::BooleanOperation;
 
+pub mod boolean_batch;
 pub mod boolean_compound_curve;
 pub mod boolean_curve;
+pub mod boolean_planar_region;
 pub mod boolean_region;
 mod clip;
-mod degeneracies;
+pub(crate) mod degeneracies;
 mod has_parameter;
 pub mod node;
 pub mod operation;
@@ -29,6 +31,10 @@ pub trait Boolean<T> {
         self.boolean(BooleanOperation::Difference, other, option)
     }
 
+    fn symmetric_difference(&self, other: T, option: Self::Option) -> Self::Output {
+        self.boolean(BooleanOperation::SymmetricDifference, other, option)
+    }
+
     fn boolean(&self, operation: BooleanOperation, other: T, option: Self::Option) -> Self::Output;
 }
 
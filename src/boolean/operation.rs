@@ -5,6 +5,8 @@ pub enum BooleanOperation {
     Union,
     Intersection,
     Difference,
+    /// Regions covered by exactly one of the operands (XOR)
+    SymmetricDifference,
 }
 
 impl Display for BooleanOperation {
@@ -13,6 +15,7 @@ impl Display for BooleanOperation {
             BooleanOperation::Union => write!(f, "Union"),
             BooleanOperation::Intersection => write!(f, "Intersection"),
             BooleanOperation::Difference => write!(f, "Difference"),
+            BooleanOperation::SymmetricDifference => write!(f, "SymmetricDifference"),
         }
     }
 }
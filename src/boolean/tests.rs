@@ -1,14 +1,15 @@
 use std::f64::consts::{PI, TAU};
 
-use nalgebra::{Point2, Vector2, U3};
+use nalgebra::{Matrix3, Point2, Point3, Vector2, Vector3, U3};
 
 use crate::{
     curve::NurbsCurve2D,
-    prelude::CurveIntersectionSolverOptions,
-    region::{CompoundCurve, Region},
+    misc::Transformable,
+    prelude::{Contains, CurveIntersectionSolverOptions},
+    region::{CompoundCurve, PlanarRegion, Region},
 };
 
-use super::Boolean;
+use super::{operation::BooleanOperation, Boolean};
 
 const OPTIONS: CurveIntersectionSolverOptions<f64> = CurveIntersectionSolverOptions {
     minimum_distance: 1e-4,
@@ -163,3 +164,226 @@ fn test_rectangular_annulus_x_rectangle() {
     assert_eq!(region.interiors().len(), 1);
     assert_eq!(&region.interiors()[0].spans()[0], &clip);
 }
+
+/// Test difference between regions where the subject lies inside the hole of the other
+#[test]
+fn test_rectangle_x_rectangular_annulus() {
+    let subject: Region<f64> = rectangle(0.5, 0.5).into();
+    let annulus = rectangular_annulus(2., 1.25, 1.);
+
+    // the subject does not overlap the annulus, so it remains as it is
+    let diff = subject.difference(&annulus, Some(OPTIONS)).unwrap();
+    let regions = diff.into_regions();
+    assert_eq!(regions.len(), 1);
+    assert_eq!(regions[0].exterior(), subject.exterior());
+    assert_eq!(regions[0].interiors().len(), 0);
+
+    // the subject straddling the hole keeps the part inside the hole
+    let subject: Region<f64> = rectangle(1.5, 0.5).into();
+    let diff = subject.difference(&annulus, Some(OPTIONS)).unwrap();
+    let regions = diff.into_regions();
+    assert_eq!(regions.len(), 1);
+    assert_eq!(regions[0].interiors().len(), 0);
+}
+
+/// Test symmetric difference between overlapping, nested & disjoint shapes
+#[test]
+fn test_symmetric_difference() {
+    let subject = rectangle(1., 1.);
+
+    // overlapping rectangles
+    let clip = rectangle(1., 1.).transformed(&Matrix3::new_translation(&Vector2::new(0.5, 0.5)));
+    let xor = subject.symmetric_difference(&clip, Some(OPTIONS)).unwrap();
+    let regions = xor.into_regions();
+    assert_eq!(regions.len(), 2);
+    assert!(regions.iter().all(|r| r.interiors().is_empty()));
+
+    // nested rectangles
+    let clip = rectangle(0.5, 0.5);
+    let xor = subject.symmetric_difference(&clip, Some(OPTIONS)).unwrap();
+    let regions = xor.into_regions();
+    assert_eq!(regions.len(), 1);
+    assert_eq!(&regions[0].exterior().spans()[0], &subject);
+    assert_eq!(&regions[0].interiors()[0].spans()[0], &clip);
+
+    // disjoint rectangles
+    let clip = rectangle(1., 1.).transformed(&Matrix3::new_translation(&Vector2::new(2., 0.)));
+    let xor = subject.symmetric_difference(&clip, Some(OPTIONS)).unwrap();
+    assert_eq!(xor.regions().len(), 2);
+
+    // region with a hole
+    let annulus = rectangular_annulus(2., 1.25, 0.25);
+    let xor = annulus
+        .symmetric_difference(&rectangle(0.5, 0.5), Some(OPTIONS))
+        .unwrap();
+    let regions = xor.into_regions();
+    assert_eq!(regions.len(), 2);
+
+    // the clipping routine rejects the symmetric difference instead of panicking
+    let clip_curve = rectangle(0.5, 0.5);
+    assert!(super::clip::clip(
+        &subject,
+        &clip_curve,
+        BooleanOperation::SymmetricDifference,
+        Some(OPTIONS),
+        vec![],
+    )
+    .is_err());
+}
+
+/// Test boolean operations between regions lying on a tilted plane
+#[test]
+fn test_planar_rectangular_annulus_x_rectangle() {
+    let origin = Point3::new(1., 2., 3.);
+    let x_axis = Vector3::new(1., 1., 0.).normalize();
+    let y_axis = Vector3::new(-1., 1., 1.).normalize();
+    let subject = PlanarRegion::new(origin, x_axis, y_axis, rectangular_annulus(2., 1.25, 0.25));
+
+    // the clip is placed on the same plane with the flipped frame
+    let clip = PlanarRegion::new(origin, -x_axis, y_axis, rectangle(0.5, 0.5).into());
+
+    let union = subject.union(&clip, Some(OPTIONS)).unwrap();
+    assert_eq!(union.len(), 1);
+    assert_eq!(union[0].region().interiors().len(), 0);
+
+    let intersection = subject.intersection(&clip, Some(OPTIONS)).unwrap();
+    assert_eq!(intersection.len(), 1);
+    assert_eq!(intersection[0].region().interiors().len(), 1);
+
+    let diff = subject.difference(&clip, Some(OPTIONS)).unwrap();
+    assert_eq!(diff.len(), 1);
+    assert_eq!(diff[0].region().interiors().len(), 1);
+    let plane = subject.plane();
+    let interior = &diff[0].interiors()[0];
+    interior
+        .spans()
+        .iter()
+        .flat_map(|span| span.dehomogenized_control_points())
+        .for_each(|p| assert!(plane.signed_distance(&p).abs() < 1e-8));
+
+    // regions on the different planes cannot be operated
+    let lifted = PlanarRegion::new(
+        origin + x_axis.cross(&y_axis),
+        x_axis,
+        y_axis,
+        rectangle(0.5, 0.5).into(),
+    );
+    assert!(subject.union(&lifted, Some(OPTIONS)).is_err());
+}
+
+/// Test union with a region whose hole is partly covered by the other region
+/// The covered part of the hole is filled, while the uncovered part remains as a hole.
+#[test]
+fn test_union_fills_covered_part_of_hole() {
+    let annulus = Region::new(compound_circle(2.), vec![compound_circle(1.)]);
+    let bridge = Region::from(
+        compound_circle(0.7).transformed(&Matrix3::new_translation(&Vector2::new(1.5, 0.))),
+    );
+
+    for union in [
+        bridge.union(&annulus, Some(OPTIONS)).unwrap(),
+        annulus.union(&bridge, Some(OPTIONS)).unwrap(),
+    ] {
+        let regions = union.into_regions();
+        assert_eq!(regions.len(), 1);
+        assert_eq!(regions[0].interiors().len(), 1);
+
+        // the point covered by the bridge is no longer in the hole
+        let hole = &regions[0].interiors()[0];
+        assert!(!hole.contains(&Point2::new(0.9, 0.), Some(OPTIONS)).unwrap());
+        assert!(hole
+            .contains(&Point2::new(-0.9, 0.), Some(OPTIONS))
+            .unwrap());
+    }
+}
+
+/// Test union of many regions & difference of many regions
+#[test]
+fn test_union_all_x_difference_many() {
+    // a row of overlapping circles
+    let circles = (0..6)
+        .map(|i| {
+            let translation = Vector2::new(i as f64 * 0.8 - 2., 0.);
+            let circle = compound_circle(0.5).transformed(&Matrix3::new_translation(&translation));
+            Region::from(circle)
+        })
+        .collect::<Vec<_>>();
+
+    let union = Region::union_all(&circles, Some(OPTIONS)).unwrap();
+    assert_eq!(union.len(), 1);
+    assert_eq!(union[0].interiors().len(), 0);
+
+    // the row of circles splits the thin rectangle into two parts
+    let subject: Region<f64> = rectangle(8., 0.5).into();
+    let diff = subject.difference_many(&circles, Some(OPTIONS)).unwrap();
+    assert_eq!(diff.len(), 2);
+}
+
+/// Test union of many regions given in no particular order
+/// The disjoint circles are bridged by the circles coming later.
+#[test]
+fn test_union_all_unordered() {
+    let centers = [
+        (1.6, 0.),
+        (0., 5.),
+        (10., 0.),
+        (0., 0.),
+        (0.8, 5.),
+        (0.8, 0.),
+        (0.4, 2.5),
+    ];
+    let circles = centers.map(|(x, y)| {
+        let translation = Vector2::new(x, y);
+        Region::from(compound_circle(0.5).transformed(&Matrix3::new_translation(&translation)))
+    });
+
+    let union = Region::union_all(&circles, Some(OPTIONS)).unwrap();
+    assert_eq!(union.len(), 4);
+    assert!(union.iter().all(|r| r.interiors().is_empty()));
+
+    // every circle center is covered by exactly one united region
+    for (x, y) in centers {
+        let center = Point2::new(x, y);
+        let covered = union
+            .iter()
+            .filter(|r| r.exterior().contains(&center, Some(OPTIONS)).unwrap())
+            .count();
+        assert_eq!(covered, 1);
+    }
+}
+
+/// Test union of many regions & difference of many regions with holes & disjoint results
+#[test]
+fn test_union_all_x_difference_many_with_holes() {
+    let annulus = Region::new(compound_circle(2.), vec![compound_circle(1.)]);
+    let disk = Region::from(compound_circle(0.5));
+
+    // the disk inside the hole does not fill the hole
+    let union = Region::union_all(&[annulus.clone(), disk.clone()], Some(OPTIONS)).unwrap();
+    assert_eq!(union.len(), 2);
+    let mut interiors = union
+        .iter()
+        .map(|r| r.interiors().len())
+        .collect::<Vec<_>>();
+    interiors.sort();
+    assert_eq!(interiors, vec![0, 1]);
+
+    // the disk straddling the annulus is merged into it, while the other disk stays apart
+    let bridge = Region::from(
+        compound_circle(0.7).transformed(&Matrix3::new_translation(&Vector2::new(1.5, 0.))),
+    );
+    let apart = Region::from(
+        compound_circle(0.5).transformed(&Matrix3::new_translation(&Vector2::new(4., 0.))),
+    );
+    let union = Region::union_all(&[annulus.clone(), bridge, apart], Some(OPTIONS)).unwrap();
+    assert_eq!(union.len(), 2);
+    assert!(union.iter().any(|r| r.interiors().len() == 1));
+
+    // subtracting the annulus & the disk leaves two rings
+    let subject = Region::from(compound_circle(3.));
+    let diff = subject
+        .difference_many(&[annulus, disk], Some(OPTIONS))
+        .unwrap();
+    assert_eq!(diff.len(), 2);
+    assert!(diff.iter().all(|r| r.interiors().len() == 1));
+}
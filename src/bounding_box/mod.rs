@@ -9,12 +9,15 @@ pub use curve_bounding_box_tree::*;
 pub use surface_bounding_box_tree::*;
 
 use nalgebra::{
-    allocator::Allocator, DefaultAllocator, DimName, DimNameDiff, DimNameSub, OPoint, OVector, U1,
+    allocator::Allocator, Const, DefaultAllocator, DimName, DimNameDiff, DimNameSub, OPoint,
+    OVector, U1,
 };
 use simba::scalar::SupersetOf;
 
 use crate::{
-    curve::nurbs_curve::NurbsCurve, misc::FloatingPoint, region::CompoundCurve,
+    curve::nurbs_curve::NurbsCurve,
+    misc::FloatingPoint,
+    region::{CompoundCurve, Region},
     surface::NurbsSurface,
 };
 
@@ -220,6 +223,13 @@ where
     }
 }
 
+impl<'a, T: FloatingPoint> From<&'a Region<T>> for BoundingBox<T, Const<2>> {
+    /// The bounding box of the region is the one of the exterior curve
+    fn from(value: &'a Region<T>) -> Self {
+        value.exterior().into()
+    }
+}
+
 impl<'a, T: FloatingPoint, D: DimName> From<&'a NurbsSurface<T, D>>
     for BoundingBox<T, DimNameDiff<D, U1>>
 where
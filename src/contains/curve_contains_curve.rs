@@ -25,7 +25,8 @@ impl<T: FloatingPoint + ArgminFloat> Contains<NurbsCurve<T, Const<3>>> for Nurbs
                 // use robust contains method
                 let l0 = to_line_string_helper(&self.dehomogenized_control_points());
                 let l1 = to_line_string_helper(&other.dehomogenized_control_points());
-                Ok(geo::Contains::contains(&l0, &l1))
+                let polygon = geo::Polygon::new(l0, vec![]);
+                Ok(geo::Contains::contains(&polygon, &l1))
             }
             _ => {
                 let pt = other.first_point();
@@ -151,3 +151,26 @@ fn test_problem_case_3() {
     let contains = clip.contains(&point, Some(OPTIONS)).unwrap();
     assert!(!contains);
 }
+
+#[test]
+fn test_nested_rectangles() {
+    let rectangle = |size: f64| {
+        let d = size * 0.5;
+        NurbsCurve2D::<f64>::polyline(
+            &[
+                Point2::new(-d, -d),
+                Point2::new(d, -d),
+                Point2::new(d, d),
+                Point2::new(-d, d),
+                Point2::new(-d, -d),
+            ],
+            true,
+        )
+    };
+    let outer = rectangle(2.);
+    let inner = rectangle(1.);
+
+    // the inner polyline lies in the area enclosed by the outer one, not on the outer line string
+    assert!(outer.contains(&inner, Some(OPTIONS)).unwrap());
+    assert!(!inner.contains(&outer, Some(OPTIONS)).unwrap());
+}
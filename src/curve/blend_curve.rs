@@ -0,0 +1,269 @@
+use nalgebra::{
+    allocator::Allocator, DefaultAllocator, DimName, DimNameAdd, DimNameDiff, DimNameSub, OPoint,
+    OVector, U1,
+};
+
+use crate::{curve::NurbsCurve, misc::FloatingPoint};
+
+/// Continuity at the joint of the blend curve
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BlendContinuity {
+    /// Positional continuity
+    G0,
+    /// Tangent continuity
+    G1,
+    /// Curvature continuity
+    #[default]
+    G2,
+}
+
+impl BlendContinuity {
+    /// Number of the derivatives matched at the joint
+    fn order(&self) -> usize {
+        match self {
+            BlendContinuity::G0 => 0,
+            BlendContinuity::G1 => 1,
+            BlendContinuity::G2 => 2,
+        }
+    }
+}
+
+/// Builder of the curve blending the end of a curve to the start of another curve
+/// The blend curve is a Bezier curve whose degree is determined by the continuities at both ends.
+/// The magnitudes scale the tangent vectors at the ends relative to the distance between the curves.
+#[derive(Debug, Clone)]
+pub struct BlendCurve<'a, T: FloatingPoint, D: DimName>
+where
+    DefaultAllocator: Allocator<D>,
+{
+    a: &'a NurbsCurve<T, D>,
+    b: &'a NurbsCurve<T, D>,
+    start_continuity: BlendContinuity,
+    end_continuity: BlendContinuity,
+    start_magnitude: T,
+    end_magnitude: T,
+}
+
+impl<'a, T: FloatingPoint, D: DimName> BlendCurve<'a, T, D>
+where
+    D: DimNameSub<U1>,
+    <D as DimNameSub<U1>>::Output: DimNameAdd<U1>,
+    DefaultAllocator: Allocator<D>,
+    DefaultAllocator: Allocator<DimNameDiff<D, U1>>,
+    DefaultAllocator: Allocator<<<D as DimNameSub<U1>>::Output as DimNameAdd<U1>>::Output>,
+{
+    /// Create a builder blending the end of `a` to the start of `b` with G2 continuity
+    pub fn new(a: &'a NurbsCurve<T, D>, b: &'a NurbsCurve<T, D>) -> Self {
+        Self {
+            a,
+            b,
+            start_continuity: BlendContinuity::default(),
+            end_continuity: BlendContinuity::default(),
+            start_magnitude: T::one(),
+            end_magnitude: T::one(),
+        }
+    }
+
+    pub fn start_continuity(&self) -> BlendContinuity {
+        self.start_continuity
+    }
+
+    pub fn end_continuity(&self) -> BlendContinuity {
+        self.end_continuity
+    }
+
+    pub fn start_magnitude(&self) -> T {
+        self.start_magnitude
+    }
+
+    pub fn end_magnitude(&self) -> T {
+        self.end_magnitude
+    }
+
+    /// Set the continuity at both ends
+    pub fn with_continuity(mut self, continuity: BlendContinuity) -> Self {
+        self.start_continuity = continuity;
+        self.end_continuity = continuity;
+        self
+    }
+
+    pub fn with_start_continuity(mut self, continuity: BlendContinuity) -> Self {
+        self.start_continuity = continuity;
+        self
+    }
+
+    pub fn with_end_continuity(mut self, continuity: BlendContinuity) -> Self {
+        self.end_continuity = continuity;
+        self
+    }
+
+    pub fn with_start_magnitude(mut self, magnitude: T) -> Self {
+        self.start_magnitude = magnitude;
+        self
+    }
+
+    pub fn with_end_magnitude(mut self, magnitude: T) -> Self {
+        self.end_magnitude = magnitude;
+        self
+    }
+
+    /// Try to build the blend curve
+    /// # Example
+    /// ```
+    /// use curvo::prelude::*;
+    /// use nalgebra::{Point2, Vector2};
+    /// use approx::assert_relative_eq;
+    ///
+    /// let a = NurbsCurve2D::try_arc(&Point2::origin(), &Vector2::x(), &Vector2::y(), 1., 0., std::f64::consts::FRAC_PI_2).unwrap();
+    /// let b = NurbsCurve2D::polyline(&[Point2::new(-2., 2.), Point2::new(-2., 4.)], false);
+    ///
+    /// let blend = BlendCurve::new(&a, &b)
+    ///     .with_continuity(BlendContinuity::G2)
+    ///     .with_start_magnitude(0.8)
+    ///     .try_build()
+    ///     .unwrap();
+    /// assert_eq!(blend.degree(), 5);
+    ///
+    /// let (start, end) = blend.knots_domain();
+    /// assert_relative_eq!(blend.point_at(start), Point2::new(0., 1.), epsilon = 1e-8);
+    /// assert_relative_eq!(blend.point_at(end), Point2::new(-2., 2.), epsilon = 1e-8);
+    ///
+    /// // the curvature matches the unit arc at the start & the line at the end
+    /// assert_relative_eq!(blend.curvature_at(start).unwrap().kappa(), 1., epsilon = 1e-8);
+    /// assert_relative_eq!(blend.curvature_at(end).unwrap().kappa(), 0., epsilon = 1e-8);
+    /// ```
+    pub fn try_build(&self) -> anyhow::Result<NurbsCurve<T, D>> {
+        anyhow::ensure!(
+            self.start_magnitude > T::zero() && self.end_magnitude > T::zero(),
+            "Magnitudes must be positive, but got {} and {}",
+            self.start_magnitude,
+            self.end_magnitude
+        );
+
+        let (_, a_end) = self.a.knots_domain();
+        let (b_start, _) = self.b.knots_domain();
+        let p0 = self.a.point_at(a_end);
+        let p1 = self.b.point_at(b_start);
+        let chord = (&p1 - &p0).norm();
+
+        let (m, n) = (self.start_continuity.order(), self.end_continuity.order());
+        let degree = m + n + 1;
+
+        let head = end_control_points(
+            self.a,
+            a_end,
+            self.start_continuity,
+            chord * self.start_magnitude,
+            degree,
+        )?;
+        let mut tail = end_control_points(
+            self.b,
+            b_start,
+            self.end_continuity,
+            -chord * self.end_magnitude,
+            degree,
+        )?;
+        tail.reverse();
+
+        let points = head.into_iter().chain(tail).collect::<Vec<_>>();
+        Ok(NurbsCurve::bezier(&points))
+    }
+}
+
+/// Compute the control points of the Bezier curve of the degree near the end
+/// which match the derivatives of the curve at the parameter up to the continuity.
+/// The speed is the signed length of the first derivative of the Bezier curve at the end,
+/// negative when the Bezier curve arrives at the end.
+fn end_control_points<T: FloatingPoint, D>(
+    curve: &NurbsCurve<T, D>,
+    parameter: T,
+    continuity: BlendContinuity,
+    speed: T,
+    degree: usize,
+) -> anyhow::Result<Vec<OPoint<T, DimNameDiff<D, U1>>>>
+where
+    D: DimName + DimNameSub<U1>,
+    DefaultAllocator: Allocator<D>,
+    DefaultAllocator: Allocator<DimNameDiff<D, U1>>,
+{
+    let p0 = curve.point_at(parameter);
+    if continuity == BlendContinuity::G0 {
+        return Ok(vec![p0]);
+    }
+
+    let curvature = curve.curvature_at(parameter)?;
+    let n = T::from_usize(degree).unwrap();
+
+    // B'(0) = n (P1 - P0) = speed * T
+    let p1 = &p0 + curvature.tangent_vector() * (speed / n);
+    if continuity == BlendContinuity::G1 {
+        return Ok(vec![p0, p1]);
+    }
+
+    // B''(0) = n (n - 1) (P2 - 2 P1 + P0) = speed^2 * K
+    let k: OVector<T, DimNameDiff<D, U1>> =
+        curvature.curvature_vector() * (speed * speed / (n * (n - T::one())));
+    let p2 = &p1 + (&p1 - &p0) + k;
+    Ok(vec![p0, p1, p2])
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+    use nalgebra::{Point3, Vector3};
+
+    use crate::curve::{BlendContinuity, BlendCurve, NurbsCurve3D};
+
+    #[test]
+    fn blend_continuities() {
+        let a =
+            NurbsCurve3D::<f64>::try_circle(&Point3::origin(), &Vector3::x(), &Vector3::y(), 2.)
+                .unwrap();
+        let b = NurbsCurve3D::try_interpolate(
+            &[
+                Point3::new(3., 1., 1.),
+                Point3::new(4., 2., 1.5),
+                Point3::new(5., 1., 2.),
+                Point3::new(6., 3., 2.),
+            ],
+            3,
+        )
+        .unwrap();
+        let (a_end, b_start) = (a.knots_domain().1, b.knots_domain().0);
+
+        for (continuity, degree) in [
+            (BlendContinuity::G0, 1),
+            (BlendContinuity::G1, 3),
+            (BlendContinuity::G2, 5),
+        ] {
+            let blend = BlendCurve::new(&a, &b)
+                .with_continuity(continuity)
+                .with_end_magnitude(1.5)
+                .try_build()
+                .unwrap();
+            assert_eq!(blend.degree(), degree);
+
+            let (start, end) = blend.knots_domain();
+            assert_relative_eq!(blend.point_at(start), a.point_at(a_end), epsilon = 1e-8);
+            assert_relative_eq!(blend.point_at(end), b.point_at(b_start), epsilon = 1e-8);
+            if continuity == BlendContinuity::G0 {
+                continue;
+            }
+
+            let (c0, c1) = (
+                blend.curvature_at(start).unwrap(),
+                blend.curvature_at(end).unwrap(),
+            );
+            let (a0, b1) = (
+                a.curvature_at(a_end).unwrap(),
+                b.curvature_at(b_start).unwrap(),
+            );
+            assert_relative_eq!(c0.tangent_vector(), a0.tangent_vector(), epsilon = 1e-8);
+            assert_relative_eq!(c1.tangent_vector(), b1.tangent_vector(), epsilon = 1e-8);
+            if continuity == BlendContinuity::G2 {
+                assert_relative_eq!(c0.curvature_vector(), a0.curvature_vector(), epsilon = 1e-8);
+                assert_relative_eq!(c1.curvature_vector(), b1.curvature_vector(), epsilon = 1e-8);
+            }
+        }
+    }
+}
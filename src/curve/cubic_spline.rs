@@ -0,0 +1,168 @@
+use itertools::Itertools;
+use nalgebra::{
+    allocator::Allocator, DefaultAllocator, DimName, DimNameAdd, DimNameDiff, DimNameSub, OPoint,
+    OVector, U1,
+};
+
+use crate::{
+    curve::{KnotStyle, NurbsCurve},
+    knot::KnotVector,
+    misc::FloatingPoint,
+};
+
+impl<T: FloatingPoint, D: DimName> NurbsCurve<T, D>
+where
+    D: DimNameSub<U1>,
+    <D as DimNameSub<U1>>::Output: DimNameAdd<U1>,
+    DefaultAllocator: Allocator<D>,
+    DefaultAllocator: Allocator<DimNameDiff<D, U1>>,
+    DefaultAllocator: Allocator<<<D as DimNameSub<U1>>::Output as DimNameAdd<U1>>::Output>,
+{
+    /// Try to create a cubic Hermite spline passing through the points with the tangents
+    /// Each pair of the consecutive points is connected by a cubic Bezier segment spanning a unit interval of the parameter,
+    /// so the tangents are the derivatives of the curve at the integer parameters.
+    /// # Example
+    /// ```
+    /// use curvo::prelude::*;
+    /// use nalgebra::{Point2, Vector2};
+    /// use approx::assert_relative_eq;
+    ///
+    /// let points = vec![Point2::new(0., 0.), Point2::new(2., 1.), Point2::new(4., 0.)];
+    /// let tangents = vec![Vector2::new(1., 2.), Vector2::new(2., 0.), Vector2::new(1., -2.)];
+    /// let curve = NurbsCurve2D::try_hermite(&points, &tangents).unwrap();
+    /// assert_eq!(curve.degree(), 3);
+    /// assert_eq!(curve.knots_domain(), (0., 2.));
+    ///
+    /// for (i, (p, t)) in points.iter().zip(tangents.iter()).enumerate() {
+    ///     assert_relative_eq!(curve.point_at(i as f64), p, epsilon = 1e-8);
+    ///     assert_relative_eq!(curve.tangent_at(i as f64), t, epsilon = 1e-8);
+    /// }
+    /// ```
+    pub fn try_hermite(
+        points: &[OPoint<T, DimNameDiff<D, U1>>],
+        tangents: &[OVector<T, DimNameDiff<D, U1>>],
+    ) -> anyhow::Result<Self> {
+        anyhow::ensure!(points.len() >= 2, "Too few points for Hermite spline");
+        anyhow::ensure!(
+            points.len() == tangents.len(),
+            "The number of tangents ({}) must be equal to the number of points ({})",
+            tangents.len(),
+            points.len()
+        );
+        let parameters = (0..points.len())
+            .map(|i| T::from_usize(i).unwrap())
+            .collect_vec();
+        Ok(hermite_spline(points, tangents, &parameters))
+    }
+
+    /// Try to create a Catmull-Rom spline from the control points
+    /// The curve passes through the control points except the first & last ones, which only shape the ends of the curve.
+    /// The knot style selects the uniform, chordal or centripetal parameterization by its `alpha`.
+    /// # Example
+    /// ```
+    /// use curvo::prelude::*;
+    /// use nalgebra::Point2;
+    /// use approx::assert_relative_eq;
+    ///
+    /// let points = vec![
+    ///     Point2::new(-1., 0.),
+    ///     Point2::new(0., 0.),
+    ///     Point2::new(1., 1.),
+    ///     Point2::new(1.1, 0.),
+    ///     Point2::new(3., 0.),
+    ///     Point2::new(4., 1.),
+    /// ];
+    /// let curve = NurbsCurve2D::try_catmull_rom(&points, KnotStyle::Centripetal).unwrap();
+    /// assert_eq!(curve.degree(), 3);
+    ///
+    /// // the curve passes through the inner control points
+    /// let (start, end) = curve.knots_domain();
+    /// assert_relative_eq!(curve.point_at(start), points[1], epsilon = 1e-8);
+    /// assert_relative_eq!(curve.point_at(end), points[4], epsilon = 1e-8);
+    /// for p in points[2..4].iter() {
+    ///     let t = curve.find_closest_parameter(p).unwrap();
+    ///     assert_relative_eq!(curve.point_at(t), p, epsilon = 1e-6);
+    /// }
+    /// ```
+    pub fn try_catmull_rom(
+        points: &[OPoint<T, DimNameDiff<D, U1>>],
+        knot_style: KnotStyle,
+    ) -> anyhow::Result<Self> {
+        anyhow::ensure!(
+            points.len() >= 4,
+            "Too few control points for Catmull-Rom spline"
+        );
+
+        // knot parameters spaced by the distances powered by alpha (Barry & Goldman)
+        let alpha = knot_style.alpha::<T>();
+        let mut ts = vec![T::zero()];
+        for (a, b) in points.iter().tuple_windows() {
+            let dt = (b - a).norm().powf(alpha);
+            anyhow::ensure!(
+                dt > T::default_epsilon(),
+                "Consecutive control points must not be coincident"
+            );
+            ts.push(ts[ts.len() - 1] + dt);
+        }
+
+        // tangents at the inner control points
+        let tangents = (1..(points.len() - 1))
+            .map(|i| {
+                let (p0, p1, p2) = (&points[i - 1], &points[i], &points[i + 1]);
+                let (t0, t1, t2) = (ts[i - 1], ts[i], ts[i + 1]);
+                (p1 - p0) / (t1 - t0) - (p2 - p0) / (t2 - t0) + (p2 - p1) / (t2 - t1)
+            })
+            .collect_vec();
+
+        let n = points.len();
+        Ok(hermite_spline(
+            &points[1..(n - 1)],
+            &tangents,
+            &ts[1..(n - 1)],
+        ))
+    }
+}
+
+/// Create the cubic spline connecting the points with the derivatives at the parameters by Bezier segments
+fn hermite_spline<T: FloatingPoint, D>(
+    points: &[OPoint<T, DimNameDiff<D, U1>>],
+    tangents: &[OVector<T, DimNameDiff<D, U1>>],
+    parameters: &[T],
+) -> NurbsCurve<T, D>
+where
+    D: DimName + DimNameSub<U1>,
+    <D as DimNameSub<U1>>::Output: DimNameAdd<U1>,
+    DefaultAllocator: Allocator<D>,
+    DefaultAllocator: Allocator<DimNameDiff<D, U1>>,
+    DefaultAllocator: Allocator<<<D as DimNameSub<U1>>::Output as DimNameAdd<U1>>::Output>,
+{
+    let degree = 3;
+    let d = T::from_usize(degree).unwrap();
+
+    let mut control_points = vec![points[0].clone()];
+    for i in 0..(points.len() - 1) {
+        let dt = parameters[i + 1] - parameters[i];
+        control_points.push(&points[i] + &tangents[i] * (dt / d));
+        control_points.push(&points[i + 1] - &tangents[i + 1] * (dt / d));
+        control_points.push(points[i + 1].clone());
+    }
+
+    let n = parameters.len();
+    let knots = std::iter::once(parameters[0])
+        .chain(
+            parameters
+                .iter()
+                .flat_map(|t| std::iter::repeat_n(*t, degree)),
+        )
+        .chain(std::iter::once(parameters[n - 1]))
+        .collect_vec();
+
+    NurbsCurve::new_unchecked(
+        degree,
+        control_points
+            .iter()
+            .map(|p| OPoint::from_slice(p.to_homogeneous().as_slice()))
+            .collect(),
+        KnotVector::new(knots),
+    )
+}
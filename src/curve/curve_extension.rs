@@ -0,0 +1,298 @@
+use nalgebra::{
+    allocator::Allocator, DefaultAllocator, DimName, DimNameAdd, DimNameDiff, DimNameSub, OPoint,
+    U1,
+};
+
+use crate::{
+    curve::NurbsCurve,
+    decompose::Decompose,
+    knot::KnotVector,
+    misc::{FloatingPoint, Invertible},
+    split::Split,
+};
+
+/// Maximum number of the trials to extrapolate the curve long enough for the natural extension
+const MAX_NATURAL_EXTENSION_TRIALS: usize = 32;
+
+/// End of the curve
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CurveEnd {
+    /// Start of the curve
+    Start,
+    /// End of the curve
+    #[default]
+    End,
+}
+
+/// Shape of the extension of a curve
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ExtendType {
+    /// Straight line along the tangent at the end
+    #[default]
+    Line,
+    /// Circular arc along the tangent with the curvature at the end
+    /// The arc falls back to the line if the curve is straight at the end.
+    Arc,
+    /// Extrapolation of the polynomial of the Bezier segment at the end
+    Natural,
+}
+
+impl<T: FloatingPoint, D: DimName> NurbsCurve<T, D>
+where
+    D: DimNameSub<U1>,
+    <D as DimNameSub<U1>>::Output: DimNameAdd<U1>,
+    DefaultAllocator: Allocator<D>,
+    DefaultAllocator: Allocator<DimNameDiff<D, U1>>,
+    DefaultAllocator: Allocator<<<D as DimNameSub<U1>>::Output as DimNameAdd<U1>>::Output>,
+{
+    /// Try to extend the curve beyond the end by the length
+    /// The parameters of the curve are kept, and the knot domain grows beyond the end.
+    /// The line & arc extensions continue the tangent direction (& the curvature) at the end,
+    /// while the natural extension continues the polynomial of the curve smoothly.
+    /// # Example
+    /// ```
+    /// use curvo::prelude::*;
+    /// use nalgebra::{Point2, Vector2};
+    /// use approx::assert_relative_eq;
+    /// use std::f64::consts::{FRAC_PI_2, PI};
+    ///
+    /// let arc = NurbsCurve2D::try_arc(&Point2::origin(), &Vector2::x(), &Vector2::y(), 1., 0., FRAC_PI_2).unwrap();
+    ///
+    /// // extend the end along the tangent
+    /// let line = arc.try_extend(CurveEnd::End, 2., ExtendType::Line).unwrap();
+    /// let (start, end) = line.knots_domain();
+    /// assert_relative_eq!(line.point_at(start), Point2::new(1., 0.), epsilon = 1e-8);
+    /// assert_relative_eq!(line.point_at(end), Point2::new(-2., 1.), epsilon = 1e-8);
+    ///
+    /// // extend the start along the unit circle
+    /// let circle = arc.try_extend(CurveEnd::Start, FRAC_PI_2, ExtendType::Arc).unwrap();
+    /// let (start, _) = circle.knots_domain();
+    /// assert_relative_eq!(circle.point_at(start), Point2::new(0., -1.), epsilon = 1e-8);
+    /// assert_relative_eq!(circle.try_length().unwrap(), PI, epsilon = 1e-6);
+    ///
+    /// // extrapolate the rational polynomial of the arc, which keeps on the unit circle
+    /// let natural = arc.try_extend(CurveEnd::End, 0.5, ExtendType::Natural).unwrap();
+    /// assert_relative_eq!(natural.try_length().unwrap(), FRAC_PI_2 + 0.5, epsilon = 1e-4);
+    /// let (_, end) = natural.knots_domain();
+    /// assert_relative_eq!(natural.point_at(end).coords.norm(), 1., epsilon = 1e-8);
+    /// ```
+    pub fn try_extend(
+        &self,
+        end: CurveEnd,
+        length: T,
+        extend_type: ExtendType,
+    ) -> anyhow::Result<Self> {
+        anyhow::ensure!(
+            length > T::zero(),
+            "The length must be positive, but got {}",
+            length
+        );
+
+        let mut curve = self.clone();
+        if !curve.is_clamped() {
+            curve.try_clamp()?;
+        }
+
+        let (start, last) = curve.knots_domain();
+        let t = match end {
+            CurveEnd::Start => start,
+            CurveEnd::End => last,
+        };
+        let derivative = curve.tangent_at(t);
+        let speed = derivative.norm();
+        anyhow::ensure!(
+            speed > T::default_epsilon(),
+            "The tangent at the end of the curve is degenerate"
+        );
+
+        // the extension spans the knot interval keeping the speed at the end
+        let interval = length / speed;
+        let domain = match end {
+            CurveEnd::Start => (start - interval, start),
+            CurveEnd::End => (last, last + interval),
+        };
+
+        // direction going out of the curve at the end
+        let p = curve.point_at(t);
+        let direction = match end {
+            CurveEnd::Start => -derivative / speed,
+            CurveEnd::End => derivative / speed,
+        };
+
+        let curvature = curve.curvature_at(t)?;
+        let kappa = curvature.kappa();
+        let extend_type = match extend_type {
+            ExtendType::Arc if kappa <= T::default_epsilon() => ExtendType::Line,
+            other => other,
+        };
+
+        let extension = match extend_type {
+            ExtendType::Line => {
+                let mut line = Self::polyline(&[p.clone(), &p + &direction * length], false);
+                if end == CurveEnd::Start {
+                    line.invert();
+                }
+                line.reparameterize(domain)?;
+                line
+            }
+            ExtendType::Arc => {
+                let radius = T::one() / kappa;
+                let angle = length / radius;
+                anyhow::ensure!(
+                    angle < T::two_pi(),
+                    "The arc extension of the length {} overlaps itself",
+                    length
+                );
+                let normal = curvature.curvature_vector() / kappa;
+                let center = &p + &normal * radius;
+                let mut arc =
+                    Self::try_arc(&center, &-normal, &direction, radius, T::zero(), angle)?;
+                if end == CurveEnd::Start {
+                    arc.invert();
+                }
+                arc.reparameterize(domain)?;
+                arc
+            }
+            ExtendType::Natural => curve.try_extrapolate(end, length, domain)?,
+        };
+
+        match end {
+            CurveEnd::Start => join_curves(&extension, &curve),
+            CurveEnd::End => join_curves(&curve, &extension),
+        }
+    }
+
+    /// Extrapolate the Bezier segment at the end of the curve until the extrapolated part reaches the length
+    fn try_extrapolate(&self, end: CurveEnd, length: T, domain: (T, T)) -> anyhow::Result<Self> {
+        let segments = self.try_decompose()?;
+        let segment = match end {
+            CurveEnd::Start => segments.first(),
+            CurveEnd::End => segments.last(),
+        }
+        .ok_or(anyhow::anyhow!("The curve has no segment"))?;
+        let (a, b) = segment.knots_domain();
+        let degree = segment.degree();
+        let two = T::from_usize(2).unwrap();
+
+        // the extrapolation is not proportional to the length, so widen the domain until it is long enough
+        let mut interval = domain.1 - domain.0;
+        for _ in 0..MAX_NATURAL_EXTENSION_TRIALS {
+            let (c, knots) = match end {
+                CurveEnd::Start => (a - interval, (a - interval, a)),
+                CurveEnd::End => (b + interval, (b, b + interval)),
+            };
+            let control_points =
+                extrapolate_bezier(segment.control_points(), (c - a) / (b - a), end);
+            anyhow::ensure!(
+                control_points.iter().all(|p| p[D::dim() - 1] > T::zero()),
+                "The weights of the extrapolated curve degenerate"
+            );
+            let extension = Self::new_unchecked(
+                degree,
+                control_points,
+                KnotVector::new(
+                    std::iter::repeat_n(knots.0, degree + 1)
+                        .chain(std::iter::repeat_n(knots.1, degree + 1))
+                        .collect(),
+                ),
+            );
+
+            let total = extension.try_length()?;
+            if total >= length {
+                return match end {
+                    CurveEnd::Start => {
+                        let u = extension.try_parameter_at_length(total - length, None)?;
+                        extension.try_split(u).map(|(_, tail)| tail)
+                    }
+                    CurveEnd::End => {
+                        let u = extension.try_parameter_at_length(length, None)?;
+                        extension.try_split(u).map(|(head, _)| head)
+                    }
+                };
+            }
+            interval *= two;
+        }
+
+        anyhow::bail!("Failed to extrapolate the curve to the length {}", length)
+    }
+}
+
+/// Compute the control points of the Bezier segment extrapolated to the local parameter `s` outside of [0, 1]
+/// by de Casteljau's algorithm.
+/// The points run from `s` to 0 for the start, or from 1 to `s` for the end.
+fn extrapolate_bezier<T: FloatingPoint, D>(
+    control_points: &[OPoint<T, D>],
+    s: T,
+    end: CurveEnd,
+) -> Vec<OPoint<T, D>>
+where
+    D: DimName,
+    DefaultAllocator: Allocator<D>,
+{
+    let degree = control_points.len() - 1;
+    let mut points = control_points.to_vec();
+    // the first & last points of the each level of the de Casteljau's triangle
+    let mut left = vec![points[0].clone()];
+    let mut right = vec![points[degree].clone()];
+    for k in 1..=degree {
+        for i in 0..=(degree - k) {
+            points[i] =
+                OPoint::from(&points[i].coords * (T::one() - s) + &points[i + 1].coords * s);
+        }
+        left.push(points[0].clone());
+        right.push(points[degree - k].clone());
+    }
+
+    match end {
+        CurveEnd::Start => left.into_iter().rev().collect(),
+        CurveEnd::End => right,
+    }
+}
+
+/// Join the curves whose knot domains & end points are adjacent into a single curve
+/// The curves are elevated to the same degree, and the joint has the knot of the multiplicity of the degree.
+fn join_curves<T: FloatingPoint, D>(
+    a: &NurbsCurve<T, D>,
+    b: &NurbsCurve<T, D>,
+) -> anyhow::Result<NurbsCurve<T, D>>
+where
+    D: DimName,
+    DefaultAllocator: Allocator<D>,
+{
+    let degree = a.degree().max(b.degree());
+    let a = a.try_elevate_degree(degree)?;
+    let b = b.try_elevate_degree(degree)?;
+
+    // scale the homogeneous control points of b to share the joint with a
+    let weight = |p: &OPoint<T, D>| p[D::dim() - 1];
+    let (last, first) = (
+        &a.control_points()[a.control_points().len() - 1],
+        &b.control_points()[0],
+    );
+    let ratio = weight(last) / weight(first);
+
+    let knots = a.knots().as_slice();
+    let knots = knots[..(knots.len() - degree - 1)]
+        .iter()
+        .copied()
+        .chain(std::iter::repeat_n(a.knots_domain().1, degree))
+        .chain(b.knots().as_slice()[(degree + 1)..].iter().copied())
+        .collect();
+    let control_points = a
+        .control_points()
+        .iter()
+        .cloned()
+        .chain(
+            b.control_points()
+                .iter()
+                .skip(1)
+                .map(|p| OPoint::from(&p.coords * ratio)),
+        )
+        .collect();
+
+    Ok(NurbsCurve::new_unchecked(
+        degree,
+        control_points,
+        KnotVector::new(knots),
+    ))
+}
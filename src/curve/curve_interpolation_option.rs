@@ -0,0 +1,80 @@
+use crate::curve::KnotStyle;
+
+/// Option for interpolating points by a curve
+/// The tangents give the directions of the curve at the points.
+/// Their magnitudes are normalized to the total chord length of the points, as in The NURBS Book section 9.2.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CurveInterpolationOption<V> {
+    /// Parameterization of the points
+    knot_style: KnotStyle,
+    /// Tangent at the first point
+    start_tangent: Option<V>,
+    /// Tangent at the last point
+    end_tangent: Option<V>,
+    /// Tangents at every point (Hermite-style interpolation)
+    tangents: Option<Vec<V>>,
+}
+
+impl<V> Default for CurveInterpolationOption<V> {
+    fn default() -> Self {
+        Self {
+            knot_style: KnotStyle::default(),
+            start_tangent: None,
+            end_tangent: None,
+            tangents: None,
+        }
+    }
+}
+
+impl<V> CurveInterpolationOption<V> {
+    pub fn knot_style(&self) -> KnotStyle {
+        self.knot_style
+    }
+
+    pub fn start_tangent(&self) -> Option<&V> {
+        self.start_tangent.as_ref()
+    }
+
+    pub fn end_tangent(&self) -> Option<&V> {
+        self.end_tangent.as_ref()
+    }
+
+    pub fn tangents(&self) -> Option<&Vec<V>> {
+        self.tangents.as_ref()
+    }
+
+    pub fn with_knot_style(mut self, knot_style: KnotStyle) -> Self {
+        self.knot_style = knot_style;
+        self
+    }
+
+    pub fn with_start_tangent(mut self, tangent: V) -> Self {
+        self.start_tangent = Some(tangent);
+        self
+    }
+
+    pub fn with_end_tangent(mut self, tangent: V) -> Self {
+        self.end_tangent = Some(tangent);
+        self
+    }
+
+    /// Set the tangents at every point
+    /// The tangents override the start & end tangents.
+    pub fn with_tangents(mut self, tangents: Vec<V>) -> Self {
+        self.tangents = Some(tangents);
+        self
+    }
+
+    /// Convert the type of the tangents
+    pub fn map<W, F: Fn(&V) -> W>(&self, f: F) -> CurveInterpolationOption<W> {
+        CurveInterpolationOption {
+            knot_style: self.knot_style,
+            start_tangent: self.start_tangent.as_ref().map(&f),
+            end_tangent: self.end_tangent.as_ref().map(&f),
+            tangents: self
+                .tangents
+                .as_ref()
+                .map(|tangents| tangents.iter().map(&f).collect()),
+        }
+    }
+}
@@ -5,9 +5,10 @@ use crate::misc::FloatingPoint;
 
 /// Knot parameterization for points interpolation
 /// https://en.wikipedia.org/wiki/Centripetal_Catmull%E2%80%93Rom_spline
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum KnotStyle {
     Uniform,
+    #[default]
     Chordal,
     Centripetal,
 }
@@ -51,11 +52,51 @@ impl KnotStyle {
         }
     }
 
+    /// Compute the parameters of the open sequence of points normalized into [0, 1]
+    /// Returns `None` if the points are coincident.
+    /// # Example
+    /// ```
+    /// use curvo::prelude::*;
+    /// use nalgebra::DVector;
+    /// let points = vec![
+    ///     DVector::from_vec(vec![0., 0.]),
+    ///     DVector::from_vec(vec![1., 0.]),
+    ///     DVector::from_vec(vec![5., 0.]),
+    /// ];
+    /// assert_eq!(KnotStyle::Uniform.normalized_parameters(&points), Some(vec![0., 0.5, 1.]));
+    /// assert_eq!(KnotStyle::Chordal.normalized_parameters(&points), Some(vec![0., 0.2, 1.]));
+    /// assert_eq!(KnotStyle::Centripetal.normalized_parameters(&points), Some(vec![0., 1. / 3., 1.]));
+    /// ```
+    pub fn normalized_parameters<T: FloatingPoint>(&self, points: &[DVector<T>]) -> Option<Vec<T>> {
+        let n = points.len();
+        if n < 2 {
+            return None;
+        }
+        if let KnotStyle::Uniform = self {
+            let div = T::from_usize(n - 1).unwrap();
+            return Some((0..n).map(|i| T::from_usize(i).unwrap() / div).collect());
+        }
+
+        let cumulative = std::iter::once(T::zero())
+            .chain(
+                self.parameterize(points, false)
+                    .into_iter()
+                    .scan(T::zero(), |acc, d| {
+                        *acc += d;
+                        Some(*acc)
+                    }),
+            )
+            .collect_vec();
+        let total = cumulative[n - 1];
+        (total > T::default_epsilon()).then(|| cumulative.into_iter().map(|u| u / total).collect())
+    }
+
+    /// Exponent of the distances between the points spacing the parameters
     pub fn alpha<T: FloatingPoint>(&self) -> T {
         match self {
+            KnotStyle::Uniform => T::zero(),
             KnotStyle::Chordal => T::one(),
             KnotStyle::Centripetal => T::from_f64(0.5).unwrap(),
-            _ => unimplemented!(),
         }
     }
 }
@@ -1,6 +1,13 @@
+pub mod blend_curve;
+pub mod cubic_spline;
+pub mod curve_extension;
+pub mod curve_interpolation_option;
 pub mod curve_length_parameter;
 pub mod knot_style;
 pub mod nurbs_curve;
+pub use blend_curve::*;
+pub use curve_extension::*;
+pub use curve_interpolation_option::*;
 pub use curve_length_parameter::*;
 pub use knot_style::*;
 pub use nurbs_curve::*;
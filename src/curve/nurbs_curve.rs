@@ -13,13 +13,27 @@ use simba::scalar::SupersetOf;
 
 use crate::misc::binomial::Binomial;
 use crate::misc::frenet_frame::FrenetFrame;
+use crate::misc::linear_algebra::{basis_matrix, try_solve_least_squares};
 use crate::misc::transformable::Transformable;
 use crate::misc::trigonometry::segment_closest_point;
 use crate::misc::{Curvature, Ray};
 use crate::prelude::{CurveLengthParameter, Decompose, Invertible, KnotVector};
+use crate::split::Split;
 use crate::{misc::FloatingPoint, CurveClosestParameterNewton, CurveClosestParameterProblem};
 
-use super::KnotStyle;
+use super::{CurveInterpolationOption, KnotStyle};
+
+/// Number of the samples per degree to fit & measure the curve of the reduced degree
+const DEGREE_REDUCTION_SAMPLES: usize = 4;
+
+/// Maximum number of the subdivisions of the curve domain to reduce the degree within the tolerance
+const MAX_DEGREE_REDUCTION_SUBDIVISION: usize = 1 << 12;
+
+/// Number of the samples per degree on each Bezier segment to reparameterize the curve by the arc length
+const ARC_LENGTH_SAMPLES: usize = 8;
+
+/// Maximum number of the subdivisions of the curve domain to reparameterize the curve by the arc length
+const MAX_ARC_LENGTH_SUBDIVISION: usize = 1 << 12;
 
 /// NURBS curve representation
 /// By generics, it can be used for 2D or 3D curves with f32 or f64 scalar types
@@ -510,6 +524,48 @@ where
         self.knots = KnotVector::new(normalized);
     }
 
+    /// Reparameterize the curve to the new domain by mapping the knot vector affinely
+    /// The shape of the curve is kept, and the parameter `t` of the original curve is mapped to
+    /// `new_start + (t - start) * (new_end - new_start) / (end - start)`.
+    /// # Example
+    /// ```
+    /// use curvo::prelude::*;
+    /// use nalgebra::{Point2, Vector2};
+    /// use approx::assert_relative_eq;
+    /// let unit_circle = NurbsCurve2D::try_circle(
+    ///     &Point2::origin(),
+    ///     &Vector2::x(),
+    ///     &Vector2::y(),
+    ///     1.
+    /// ).unwrap();
+    /// let mut reparameterized = unit_circle.clone();
+    /// reparameterized.reparameterize((2., 6.)).unwrap();
+    /// assert_eq!(reparameterized.knots_domain(), (2., 6.));
+    /// assert_relative_eq!(
+    ///     reparameterized.point_at(3.),
+    ///     unit_circle.point_at(std::f64::consts::FRAC_PI_2),
+    ///     epsilon = 1e-10
+    /// );
+    /// ```
+    pub fn reparameterize(&mut self, new_domain: (T, T)) -> anyhow::Result<()> {
+        let (new_start, new_end) = new_domain;
+        anyhow::ensure!(
+            new_start < new_end,
+            "The domain must be increasing, but got ({}, {})",
+            new_start,
+            new_end
+        );
+        let (start, end) = self.knots_domain();
+        let scale = (new_end - new_start) / (end - start);
+        let knots = self
+            .knots
+            .iter()
+            .map(|k| new_start + (*k - start) * scale)
+            .collect_vec();
+        self.knots = KnotVector::new(knots);
+        Ok(())
+    }
+
     /// Compute the length of the curve by gauss-legendre quadrature
     /// # Example
     /// ```
@@ -624,6 +680,154 @@ where
         ))
     }
 
+    /// Try to approximate the curve by a curve parameterized by the arc length
+    /// The parameter of the resulting curve runs over [0, length of the curve],
+    /// and evaluating it at `s` gives the point at the arc length `s` of the curve within the tolerance.
+    /// Each Bezier segment of the curve is fitted by a non-rational curve of the same degree keeping the unit speed at its ends,
+    /// segments which cannot be fitted from their samples are subdivided, and the fitted segments are merged by removing the knots between them within the tolerance.
+    /// # Example
+    /// ```
+    /// use curvo::prelude::*;
+    /// use nalgebra::{Point2, Vector2};
+    /// use approx::assert_relative_eq;
+    /// let unit_circle = NurbsCurve2D::try_circle(
+    ///     &Point2::origin(),
+    ///     &Vector2::x(),
+    ///     &Vector2::y(),
+    ///     1.
+    /// ).unwrap();
+    ///
+    /// let tolerance = 1e-4;
+    /// let mut curve = unit_circle.try_reparameterize_by_arc_length(tolerance).unwrap();
+    /// let (start, end) = curve.knots_domain();
+    /// assert_eq!(start, 0.);
+    /// assert_relative_eq!(end, std::f64::consts::TAU, epsilon = 1e-8);
+    ///
+    /// // the point at the parameter is the point at the same angle on the unit circle
+    /// for i in 0..=64 {
+    ///     let s = end * i as f64 / 64.;
+    ///     let p = curve.point_at(s);
+    ///     assert!((p - Point2::new(s.cos(), s.sin())).norm() <= tolerance);
+    /// }
+    ///
+    /// // normalize the parameter to [0, 1] for the constant speed animation
+    /// curve.reparameterize((0., 1.)).unwrap();
+    /// let p = curve.point_at(0.25);
+    /// assert!((p - Point2::new(0., 1.)).norm() <= tolerance);
+    /// ```
+    pub fn try_reparameterize_by_arc_length(&self, tolerance: T) -> anyhow::Result<Self>
+    where
+        D: DimNameSub<U1>,
+        DefaultAllocator: Allocator<DimNameDiff<D, U1>>,
+    {
+        anyhow::ensure!(
+            tolerance > T::zero(),
+            "Tolerance must be positive, but got {}",
+            tolerance
+        );
+
+        // spend the quarter of the tolerance to fit the segments to the samples, and the half to merge them
+        // the samples are located precisely enough not to disturb the fitting
+        let quarter = tolerance / T::from_usize(4).unwrap();
+        let half = tolerance / T::from_usize(2).unwrap();
+        let precision = quarter / T::from_usize(16).unwrap();
+
+        let degree = self.degree;
+        let gauss = GaussLegendre::new(16 + degree)?;
+        let n = ARC_LENGTH_SAMPLES * (degree + 1);
+        let us = (0..=n)
+            .map(|i| T::from_usize(i).unwrap() / T::from_usize(n).unwrap())
+            .collect_vec();
+        let vector = |v: &OVector<T, DimNameDiff<D, U1>>| DVector::from_column_slice(v.as_slice());
+
+        let mut segments = self.try_decompose()?;
+        segments.reverse();
+        let mut fitted = vec![];
+        while let Some(segment) = segments.pop() {
+            let (start, end) = segment.knots_domain();
+            let length = compute_bezier_segment_length(&segment, end, &gauss);
+            if length <= T::default_epsilon() {
+                continue;
+            }
+
+            // points at the equal arc lengths on the segment
+            let points = us
+                .iter()
+                .map(|u| {
+                    let t = compute_bezier_segment_parameter_at_length(
+                        &segment,
+                        *u * length,
+                        precision,
+                        length,
+                        &gauss,
+                    );
+                    vector(&segment.point_at(t).coords)
+                })
+                .collect_vec();
+
+            // derivatives with the unit speed at the ends
+            let tangent = |t: T| {
+                let v = segment.tangent_at(t);
+                let norm = v.norm();
+                (degree >= 2 && norm > T::default_epsilon()).then(|| vector(&(v * (length / norm))))
+            };
+            let (start_tangent, end_tangent) = (tangent(start), tangent(end));
+
+            match try_approximate_control_points_at(
+                &points,
+                &us,
+                degree,
+                quarter,
+                start_tangent.as_ref(),
+                end_tangent.as_ref(),
+                true,
+            ) {
+                Ok((control_points, knots)) => {
+                    fitted.push((control_points, knots, length));
+                }
+                Err(e) => {
+                    // the samples are too sparse to fit the segment bending sharply
+                    anyhow::ensure!(
+                        end - start
+                            > self.knots_domain_interval()
+                                / T::from_usize(MAX_ARC_LENGTH_SUBDIVISION).unwrap(),
+                        e
+                    );
+                    let (head, tail) =
+                        segment.try_split((start + end) / T::from_f64(2.).unwrap())?;
+                    segments.push(tail);
+                    segments.push(head);
+                }
+            }
+        }
+        anyhow::ensure!(!fitted.is_empty(), "The curve has no length");
+
+        // join the fitted segments with the knots of the multiplicity of the degree
+        let mut knots = vec![T::zero(); degree + 1];
+        let mut control_points = vec![];
+        let mut offset = T::zero();
+        for (i, (points, segment_knots, length)) in fitted.iter().enumerate() {
+            if i > 0 {
+                knots.extend(std::iter::repeat_n(offset, degree));
+            }
+            let interior =
+                &segment_knots.as_slice()[(degree + 1)..(segment_knots.len() - degree - 1)];
+            knots.extend(interior.iter().map(|k| offset + *k * *length));
+            control_points.extend(
+                points
+                    .iter()
+                    .skip(if i == 0 { 0 } else { 1 })
+                    .map(|p| OPoint::from_slice(p.as_slice())),
+            );
+            offset += *length;
+        }
+        knots.extend(std::iter::repeat_n(offset, degree + 1));
+
+        let mut curve = Self::new_unchecked(degree, control_points, KnotVector::new(knots));
+        curve.try_reduce_knots(Some(half))?;
+        Ok(curve)
+    }
+
     /// Divide a NURBS curve by a given length
     /// # Example
     /// ```
@@ -825,6 +1029,70 @@ where
         })
     }
 
+    /// Try to create an interpolated NURBS curve from a set of points
+    /// with the knot style & the tangent constraints of the option
+    /// # Example
+    /// ```
+    /// use curvo::prelude::*;
+    /// use nalgebra::{Point2, Vector2};
+    /// use approx::assert_relative_eq;
+    ///
+    /// let points = vec![
+    ///     Point2::new(0., 0.),
+    ///     Point2::new(1., 1.),
+    ///     Point2::new(2., 0.),
+    ///     Point2::new(3., 1.),
+    /// ];
+    ///
+    /// // constrain the tangents at the ends
+    /// let option = CurveInterpolationOption::default()
+    ///     .with_knot_style(KnotStyle::Centripetal)
+    ///     .with_start_tangent(Vector2::x())
+    ///     .with_end_tangent(Vector2::y());
+    /// let curve = NurbsCurve2D::try_interpolate_with_option(&points, 3, option).unwrap();
+    /// let (start, end) = curve.knots_domain();
+    /// assert_relative_eq!(curve.tangent_at(start).normalize(), Vector2::x(), epsilon = 1e-8);
+    /// assert_relative_eq!(curve.tangent_at(end).normalize(), Vector2::y(), epsilon = 1e-8);
+    ///
+    /// // constrain the tangents at every point
+    /// let tangents = vec![Vector2::y(), Vector2::x(), -Vector2::x(), Vector2::y()];
+    /// let option = CurveInterpolationOption::default().with_tangents(tangents.clone());
+    /// let curve = NurbsCurve2D::try_interpolate_with_option(&points, 3, option).unwrap();
+    /// for (p, t) in points.iter().zip(tangents.iter()) {
+    ///     let u = curve.find_closest_parameter(p).unwrap();
+    ///     assert_relative_eq!(curve.point_at(u), p, epsilon = 1e-6);
+    ///     assert_relative_eq!(curve.tangent_at(u).normalize(), t, epsilon = 1e-6);
+    /// }
+    /// ```
+    pub fn try_interpolate_with_option(
+        points: &[OPoint<T, DimNameDiff<D, U1>>],
+        degree: usize,
+        option: CurveInterpolationOption<OVector<T, DimNameDiff<D, U1>>>,
+    ) -> anyhow::Result<Self>
+    where
+        DefaultAllocator: Allocator<D>,
+        D: DimNameSub<U1>,
+        DefaultAllocator: Allocator<DimNameDiff<D, U1>>,
+    {
+        let (control_points, knots) = try_interpolate_control_points_with_option(
+            &points
+                .iter()
+                .map(|p| DVector::from_vec(p.iter().copied().collect()))
+                .collect_vec(),
+            degree,
+            &option.map(|t| DVector::from_column_slice(t.as_slice())),
+            true,
+        )?;
+        Ok(Self {
+            degree,
+            control_points: control_points
+                .iter()
+                .map(|v| OPoint::from_slice(v.as_slice()))
+                .collect(),
+            knots,
+        })
+    }
+
     /// Try to create an periodic interpolated NURBS curve from a set of points
     /// # Example
     /// ```
@@ -878,6 +1146,107 @@ where
         )
     }
 
+    /// Try to create a NURBS curve approximating a set of points within the tolerance
+    /// The curve starts from a Bezier curve & knots are inserted adaptively into the spans deviating the most from the points
+    /// until the deviation at every point is within the tolerance.
+    /// The curve passes through the first & last points.
+    /// # Example
+    /// ```
+    /// use curvo::prelude::*;
+    /// use nalgebra::Point2;
+    ///
+    /// // noisy samples of a sine wave
+    /// let points = (0..=100)
+    ///     .map(|i| {
+    ///         let x = i as f64 / 100. * std::f64::consts::TAU;
+    ///         let noise = ((i * 37) % 11) as f64 / 11. * 1e-3;
+    ///         Point2::new(x, x.sin() + noise)
+    ///     })
+    ///     .collect::<Vec<_>>();
+    /// let tolerance = 1e-2;
+    /// let curve = NurbsCurve2D::try_approximate(&points, 3, tolerance).unwrap();
+    /// assert!(curve.control_points().len() < points.len() / 4);
+    ///
+    /// for p in points.iter() {
+    ///     let t = curve.find_closest_parameter(p).unwrap();
+    ///     assert!((curve.point_at(t) - p).norm() <= tolerance);
+    /// }
+    /// ```
+    pub fn try_approximate(
+        points: &[OPoint<T, DimNameDiff<D, U1>>],
+        degree: usize,
+        tolerance: T,
+    ) -> anyhow::Result<Self>
+    where
+        D: DimNameSub<U1>,
+        DefaultAllocator: Allocator<D>,
+        DefaultAllocator: Allocator<DimNameDiff<D, U1>>,
+    {
+        Self::try_approximate_with_tangents(points, degree, tolerance, None, None)
+    }
+
+    /// Try to create a NURBS curve approximating a set of points within the tolerance
+    /// with the optional tangent directions at the start & end of the curve
+    /// # Example
+    /// ```
+    /// use curvo::prelude::*;
+    /// use nalgebra::{Point2, Vector2};
+    /// use approx::assert_relative_eq;
+    ///
+    /// let points = (0..=50)
+    ///     .map(|i| {
+    ///         let x = i as f64 / 50. * 2.;
+    ///         Point2::new(x, x * x)
+    ///     })
+    ///     .collect::<Vec<_>>();
+    /// let curve = NurbsCurve2D::try_approximate_with_tangents(
+    ///     &points,
+    ///     3,
+    ///     1e-3,
+    ///     Some(&Vector2::x()),
+    ///     Some(&Vector2::new(1., 4.)),
+    /// )
+    /// .unwrap();
+    ///
+    /// let (start, end) = curve.knots_domain();
+    /// assert_relative_eq!(curve.tangent_at(start).normalize(), Vector2::x(), epsilon = 1e-8);
+    /// assert_relative_eq!(
+    ///     curve.tangent_at(end).normalize(),
+    ///     Vector2::new(1., 4.).normalize(),
+    ///     epsilon = 1e-8
+    /// );
+    /// ```
+    pub fn try_approximate_with_tangents(
+        points: &[OPoint<T, DimNameDiff<D, U1>>],
+        degree: usize,
+        tolerance: T,
+        start_tangent: Option<&OVector<T, DimNameDiff<D, U1>>>,
+        end_tangent: Option<&OVector<T, DimNameDiff<D, U1>>>,
+    ) -> anyhow::Result<Self>
+    where
+        D: DimNameSub<U1>,
+        DefaultAllocator: Allocator<D>,
+        DefaultAllocator: Allocator<DimNameDiff<D, U1>>,
+    {
+        let vector = |v: &OVector<T, DimNameDiff<D, U1>>| DVector::from_column_slice(v.as_slice());
+        let (control_points, knots) = try_approximate_control_points(
+            &points.iter().map(|p| vector(&p.coords)).collect_vec(),
+            degree,
+            tolerance,
+            start_tangent.map(vector).as_ref(),
+            end_tangent.map(vector).as_ref(),
+            true,
+        )?;
+        Self::try_new(
+            degree,
+            control_points
+                .iter()
+                .map(|v| OPoint::from_slice(v.as_slice()))
+                .collect(),
+            knots.to_vec(),
+        )
+    }
+
     /// Try to create a circle curve
     /// # Example
     /// ```
@@ -1162,7 +1531,8 @@ where
             .to_usize()
             .unwrap();
 
-        let mut q_w = vec![OPoint::origin(); new_control_point_count];
+        // each knot span adds `degree_inc` control points at most
+        let mut q_w = vec![OPoint::origin(); control_points.len() * (degree_inc + 1) + 1];
         let mut u_h = vec![T::zero(); q_w.len() + target_degree + 1];
 
         bezalfs[0][0] = T::one();
@@ -1173,14 +1543,14 @@ where
         for i in 1..=ph2 {
             let inv = T::one() / binom.get(ph, i);
             let mpi = new_degree.min(i);
-            for j in 0.max(i - degree_inc)..=mpi {
+            for j in i.saturating_sub(degree_inc)..=mpi {
                 bezalfs[i][j] = inv * binom.get(new_degree, j) * binom.get(degree_inc, i - j);
             }
         }
 
         for i in (ph2 + 1)..ph {
             let mpi = new_degree.min(i);
-            for j in 0.max(i - degree_inc)..=mpi {
+            for j in i.saturating_sub(degree_inc)..=mpi {
                 bezalfs[i][j] = bezalfs[ph - i][new_degree - j];
             }
         }
@@ -1249,7 +1619,7 @@ where
             for i in lbz..=ph {
                 e_bpts[i] = OPoint::origin();
                 let mpi = new_degree.min(i);
-                for j in 0.max(i - degree_inc)..=mpi {
+                for j in i.saturating_sub(degree_inc)..=mpi {
                     e_bpts[i].coords = &e_bpts[i].coords + &bpts[j].coords * bezalfs[i][j];
                 }
             }
@@ -1314,6 +1684,9 @@ where
             }
         }
 
+        q_w.truncate(cind);
+        u_h.truncate(cind + target_degree + 1);
+
         Ok(Self {
             degree: target_degree,
             control_points: q_w,
@@ -1321,6 +1694,112 @@ where
         })
     }
 
+    /// Try to reduce the degree of the curve within the tolerance
+    /// The curve is decomposed into Bezier segments, and each segment is reduced to the target degree
+    /// keeping its end points & end tangents (if the target degree allows) & fitting the rest in the least squares sense.
+    /// Segments deviating more than the tolerance are subdivided.
+    /// The reduced segments are merged by removing the knots between them within the tolerance.
+    /// Returns the reduced curve & the achieved maximum deviation from the curve.
+    /// # Example
+    /// ```
+    /// use curvo::prelude::*;
+    /// use nalgebra::Point3;
+    ///
+    /// let points = (0..8)
+    ///     .map(|i| {
+    ///         let t = i as f64 / 7. * std::f64::consts::PI;
+    ///         Point3::new(t, t.sin(), (t * 2.).cos() * 0.5)
+    ///     })
+    ///     .collect::<Vec<_>>();
+    /// let curve = NurbsCurve3D::try_interpolate(&points, 6).unwrap();
+    ///
+    /// let tolerance = 1e-4;
+    /// let (reduced, error) = curve.try_reduce_degree(3, tolerance).unwrap();
+    /// assert_eq!(reduced.degree(), 3);
+    /// assert!(error <= tolerance);
+    ///
+    /// let (start, end) = curve.knots_domain();
+    /// assert_eq!(reduced.knots_domain(), (start, end));
+    /// for i in 0..=100 {
+    ///     let t = start + (end - start) * i as f64 / 100.;
+    ///     assert!((reduced.point_at(t) - curve.point_at(t)).norm() <= tolerance);
+    /// }
+    /// ```
+    pub fn try_reduce_degree(&self, target_degree: usize, tolerance: T) -> anyhow::Result<(Self, T)>
+    where
+        D: DimNameSub<U1>,
+        DefaultAllocator: Allocator<DimNameDiff<D, U1>>,
+    {
+        anyhow::ensure!(target_degree >= 1, "The target degree must be at least 1");
+        anyhow::ensure!(
+            tolerance > T::zero(),
+            "Tolerance must be positive, but got {}",
+            tolerance
+        );
+        if target_degree >= self.degree {
+            return Ok((self.clone(), T::zero()));
+        }
+
+        // spend the half of the tolerance to reduce the segments & the rest to merge them
+        let half = tolerance / T::from_f64(2.).unwrap();
+        let mut segments = self.try_decompose()?;
+        segments.reverse();
+        let mut reduced = vec![];
+        while let Some(segment) = segments.pop() {
+            let candidate = try_reduce_bezier_degree(&segment, target_degree)?;
+            if deviation(&segment, &candidate) <= half {
+                reduced.push(candidate);
+                continue;
+            }
+
+            let (start, end) = segment.knots_domain();
+            anyhow::ensure!(
+                end - start
+                    > self.knots_domain_interval()
+                        / T::from_usize(MAX_DEGREE_REDUCTION_SUBDIVISION).unwrap(),
+                "Failed to reduce the degree within the tolerance {}",
+                tolerance
+            );
+            let (head, tail) = segment.try_split((start + end) / T::from_f64(2.).unwrap())?;
+            segments.push(tail);
+            segments.push(head);
+        }
+
+        // join the segments with the knots of the multiplicity of the degree
+        let (start, end) = self.knots_domain();
+        let knots =
+            std::iter::repeat_n(start, target_degree + 1)
+                .chain(reduced.iter().skip(1).flat_map(|segment| {
+                    std::iter::repeat_n(segment.knots_domain().0, target_degree)
+                }))
+                .chain(std::iter::repeat_n(end, target_degree + 1))
+                .collect_vec();
+        let control_points = reduced
+            .iter()
+            .enumerate()
+            .flat_map(|(i, segment)| {
+                segment
+                    .control_points
+                    .iter()
+                    .skip(if i == 0 { 0 } else { 1 })
+                    .cloned()
+            })
+            .collect_vec();
+        let joined = Self::new_unchecked(target_degree, control_points, KnotVector::new(knots));
+        let joined_error = deviation(self, &joined);
+
+        let mut merged = joined.clone();
+        merged.try_reduce_knots(Some(half))?;
+        let merged_error = deviation(self, &merged);
+
+        // fall back to the joined segments if merging them exceeds the tolerance
+        if merged_error <= tolerance {
+            Ok((merged, merged_error))
+        } else {
+            Ok((joined, joined_error))
+        }
+    }
+
     /// Try to add a knot to the curve
     pub fn try_add_knot(&mut self, knot: T) -> anyhow::Result<()> {
         anyhow::ensure!(
@@ -1400,6 +1879,17 @@ where
     /// assert_eq!(polyline.knots().len(), 6);
     /// ```
     pub fn try_remove_knot(&mut self, knot: T, tolerance: Option<T>) -> anyhow::Result<usize> {
+        self.try_remove_knot_times(knot, usize::MAX, tolerance)
+    }
+
+    /// Try to remove a knot from the curve at most the given number of times
+    /// Returns the number of knots removed
+    pub(crate) fn try_remove_knot_times(
+        &mut self,
+        knot: T,
+        times: usize,
+        tolerance: Option<T>,
+    ) -> anyhow::Result<usize> {
         let tolerance = tolerance.unwrap_or(T::from_f64(1e-6).unwrap());
 
         let n = self.control_points.len() - 1;
@@ -1408,8 +1898,8 @@ where
         let r = self
             .knots
             .iter()
-            .position(|k| *k == knot)
-            .ok_or(anyhow::anyhow!("Knot not found"))?; // index of the knot
+            .rposition(|k| *k == knot)
+            .ok_or(anyhow::anyhow!("Knot not found"))?; // index of the last occurrence of the knot
 
         let multiplicities = self.knots.multiplicity();
         let mult = multiplicities
@@ -1439,7 +1929,7 @@ where
         let mut t = 0usize;
 
         // use multiplicity as # of times to remove knot
-        for _ in 0..s {
+        for _ in 0..s.min(times) {
             let off = first - 1;
             temp[0] = removed_control_points[off].clone();
 
@@ -1948,26 +2438,7 @@ impl<T: FloatingPoint> NurbsCurve3D<T> {
         let mut normals = vec![Vector3::zeros()];
         let mut binormals = vec![Vector3::zeros()];
 
-        let mut normal = Vector3::zeros();
-        let tx = tangents[0].x.abs();
-        let ty = tangents[0].y.abs();
-        let tz = tangents[0].z.abs();
-
-        let mut min = T::max_value().unwrap();
-        if tx <= min {
-            min = tx;
-            normal = Vector3::x();
-        }
-        if ty <= min {
-            min = ty;
-            normal = Vector3::y();
-        }
-        if tz <= min {
-            normal = Vector3::z();
-        }
-
-        let v = tangents[0].cross(&normal).normalize();
-        normals[0] = tangents[0].cross(&v).normalize();
+        normals[0] = perpendicular_normal(&tangents[0]);
         binormals[0] = tangents[0].cross(&normals[0]).normalize();
 
         for i in 1..parameters.len() {
@@ -1997,6 +2468,148 @@ impl<T: FloatingPoint> NurbsCurve3D<T> {
             })
             .collect()
     }
+
+    /// Compute the rotation-minimizing frames of the curve at given parameters
+    /// based on the double reflection method described in the paper: https://doi.org/10.1145/1330511.1330513
+    /// The normal of the first frame is taken from the initial normal projected onto the normal plane if given.
+    /// If the curve is closed & the parameters cover the whole domain,
+    /// the twist between the first & the last frames is distributed along the curve so that the frames close up.
+    /// # Example
+    /// ```
+    /// use curvo::prelude::*;
+    /// use nalgebra::{Point3, Vector3};
+    /// use approx::assert_relative_eq;
+    ///
+    /// // the frames are well defined on the straight section & don't flip at the inflection
+    /// let curve = NurbsCurve3D::try_interpolate(&[
+    ///     Point3::new(0., 0., 0.),
+    ///     Point3::new(1., 0., 0.),
+    ///     Point3::new(2., 0., 0.),
+    ///     Point3::new(3., 1., 0.),
+    ///     Point3::new(4., 0., 0.),
+    ///     Point3::new(5., -1., 0.),
+    /// ], 3).unwrap();
+    /// let (start, end) = curve.knots_domain();
+    /// let parameters = (0..=32).map(|i| start + (end - start) * i as f64 / 32.).collect::<Vec<_>>();
+    /// let frames = curve.compute_rotation_minimizing_frames(&parameters, Some(Vector3::z()));
+    /// for frame in frames.iter() {
+    ///     assert_relative_eq!(frame.normal(), &Vector3::z(), epsilon = 1e-8);
+    ///     assert_relative_eq!(frame.tangent().dot(frame.normal()), 0., epsilon = 1e-8);
+    ///     assert_relative_eq!(frame.binormal(), &frame.tangent().cross(frame.normal()), epsilon = 1e-8);
+    /// }
+    /// ```
+    pub fn compute_rotation_minimizing_frames(
+        &self,
+        parameters: &[T],
+        initial_normal: Option<Vector3<T>>,
+    ) -> Vec<FrenetFrame<T>> {
+        let samples = parameters
+            .iter()
+            .map(|u| {
+                let deriv = self.rational_derivatives(*u, 1);
+                (OPoint::from(deriv[0]), deriv[1].normalize())
+            })
+            .collect_vec();
+        let Some((_, t0)) = samples.first() else {
+            return vec![];
+        };
+
+        let eps = T::default_epsilon();
+        let two = T::from_f64(2.).unwrap();
+        let r0 = initial_normal
+            .map(|n| n - t0 * t0.dot(&n))
+            .filter(|n| n.norm() > eps)
+            .map(|n| n.normalize())
+            .unwrap_or_else(|| perpendicular_normal(t0));
+
+        let mut normals = vec![r0];
+        for (i, w) in samples.windows(2).enumerate() {
+            let ((x0, t0), (x1, t1)) = (&w[0], &w[1]);
+            let r0 = &normals[i];
+
+            // reflect the frame by the bisecting plane of the two points
+            let v1 = x1 - x0;
+            let c1 = v1.dot(&v1);
+            let (rl, tl) = if c1 > eps {
+                (
+                    r0 - v1 * (two / c1 * v1.dot(r0)),
+                    t0 - v1 * (two / c1 * v1.dot(t0)),
+                )
+            } else {
+                (*r0, *t0)
+            };
+
+            // reflect again to align the reflected tangent with the next tangent
+            let v2 = t1 - tl;
+            let c2 = v2.dot(&v2);
+            let r1 = if c2 > eps {
+                rl - v2 * (two / c2 * v2.dot(&rl))
+            } else {
+                rl
+            };
+            normals.push((r1 - t1 * t1.dot(&r1)).normalize());
+        }
+
+        // distribute the twist over the closed curve by the chord length
+        let (start, end) = self.knots_domain();
+        let covered = parameters
+            .first()
+            .zip(parameters.last())
+            .is_some_and(|(first, last)| (*first - start).abs() < eps && (end - *last).abs() < eps);
+        if samples.len() > 2 && covered && self.is_closed() {
+            let t1 = &samples[samples.len() - 1].1;
+            let (r0, r1) = (&normals[0], &normals[normals.len() - 1]);
+            let twist = t1.dot(&r1.cross(r0)).atan2(r1.dot(r0));
+            let lengths = std::iter::once(T::zero())
+                .chain(samples.windows(2).scan(T::zero(), |acc, w| {
+                    *acc += (w[1].0 - w[0].0).norm();
+                    Some(*acc)
+                }))
+                .collect_vec();
+            let total = lengths[lengths.len() - 1];
+            if total > eps {
+                normals = normals
+                    .iter()
+                    .zip(samples.iter())
+                    .zip(lengths.iter())
+                    .map(|((r, (_, t)), l)| {
+                        let axis = UnitVector3::new_normalize(*t);
+                        Rotation3::from_axis_angle(&axis, twist * *l / total) * r
+                    })
+                    .collect();
+            }
+        }
+
+        samples
+            .into_iter()
+            .zip(normals)
+            .map(|((x, t), r)| FrenetFrame::new(x, t, r, t.cross(&r)))
+            .collect()
+    }
+}
+
+/// Find the normal perpendicular to the tangent from the axis least aligned with the tangent
+fn perpendicular_normal<T: FloatingPoint>(tangent: &Vector3<T>) -> Vector3<T> {
+    let mut normal = Vector3::zeros();
+    let tx = tangent.x.abs();
+    let ty = tangent.y.abs();
+    let tz = tangent.z.abs();
+
+    let mut min = T::max_value().unwrap();
+    if tx <= min {
+        min = tx;
+        normal = Vector3::x();
+    }
+    if ty <= min {
+        min = ty;
+        normal = Vector3::y();
+    }
+    if tz <= min {
+        normal = Vector3::z();
+    }
+
+    let v = tangent.cross(&normal).normalize();
+    tangent.cross(&v).normalize()
 }
 
 /// Find the curve parameter at arc length on a Bezier segment of a NURBS curve
@@ -2097,63 +2710,107 @@ pub fn try_interpolate_control_points<T: FloatingPoint>(
     degree: usize,
     homogeneous: bool,
 ) -> anyhow::Result<(Vec<DVector<T>>, KnotVector<T>)> {
-    let n = points.len();
-    if n < degree + 1 {
-        anyhow::bail!("Too few control points for curve");
-    }
-
-    let mut us: Vec<T> = vec![T::zero()];
-    for i in 1..n {
-        let sub = &points[i] - &points[i - 1];
-        let chord = sub.norm();
-        let last = us[i - 1];
-        us.push(last + chord);
-    }
-
-    // normalize
-    let max = us[us.len() - 1];
-    for i in 0..us.len() {
-        us[i] /= max;
-    }
-
-    let mut knots_start = vec![T::zero(); degree + 1];
-
-    let start = 1;
-    let end = us.len() - degree;
+    try_interpolate_control_points_with_option(
+        points,
+        degree,
+        &CurveInterpolationOption::default(),
+        homogeneous,
+    )
+}
 
-    for i in start..end {
-        let mut weight_sums = T::zero();
-        for j in 0..degree {
-            weight_sums += us[i + j];
+/// Compute the control points & knot vector of the curve interpolating the points
+/// with the knot style & the tangent constraints of the option
+/// Each tangent adds a control point & a row of the derivatives of the basis functions to the linear system,
+/// and the knots are averaged from the parameters where the constrained parameters are doubled.
+pub fn try_interpolate_control_points_with_option<T: FloatingPoint>(
+    points: &[DVector<T>],
+    degree: usize,
+    option: &CurveInterpolationOption<DVector<T>>,
+    homogeneous: bool,
+) -> anyhow::Result<(Vec<DVector<T>>, KnotVector<T>)> {
+    let n = points.len();
+    anyhow::ensure!(n >= 2, "Too few control points for curve");
+
+    let tangents: Vec<Option<&DVector<T>>> = match option.tangents() {
+        Some(tangents) => {
+            anyhow::ensure!(
+                tangents.len() == n,
+                "The number of tangents ({}) must be equal to the number of points ({})",
+                tangents.len(),
+                n
+            );
+            tangents.iter().map(Some).collect()
         }
-        knots_start.push(weight_sums / T::from_usize(degree).unwrap());
+        None => (0..n)
+            .map(|i| match i {
+                0 => option.start_tangent(),
+                _ if i == n - 1 => option.end_tangent(),
+                _ => None,
+            })
+            .collect(),
+    };
+    let constraints = tangents.iter().flatten().count();
+    if n + constraints < degree + 1 {
+        anyhow::bail!("Too few control points for curve");
     }
-
-    let knots = KnotVector::new([knots_start, vec![T::one(); degree + 1]].concat());
-    let plen = points.len();
-
-    let n = plen - 1;
-    let ld = plen - (degree + 1);
+    anyhow::ensure!(
+        constraints == 0 || degree >= 2,
+        "The degree must be at least 2 to interpolate the tangents"
+    );
+
+    let us = option
+        .knot_style()
+        .normalized_parameters(points)
+        .ok_or(anyhow::anyhow!("The points are coincident"))?;
+    let total = points
+        .iter()
+        .tuple_windows()
+        .fold(T::zero(), |acc, (a, b)| acc + (b - a).norm());
+
+    // the parameters with the constrained ones doubled
+    let doubled = us
+        .iter()
+        .zip(tangents.iter())
+        .flat_map(|(u, t)| std::iter::repeat_n(*u, if t.is_some() { 2 } else { 1 }))
+        .collect_vec();
+    let count = doubled.len();
+    let inner = (1..(count - degree)).map(|i| {
+        doubled[i..(i + degree)]
+            .iter()
+            .fold(T::zero(), |acc, u| acc + *u)
+            / T::from_usize(degree).unwrap()
+    });
+    let knots = KnotVector::new(
+        std::iter::repeat_n(T::zero(), degree + 1)
+            .chain(inner)
+            .chain(std::iter::repeat_n(T::one(), degree + 1))
+            .collect(),
+    );
 
     // build basis function coefficients matrix
-    let mut m_a = DMatrix::<T>::zeros(us.len(), degree + 1 + ld);
-
-    for i in 0..us.len() {
-        let u = us[i];
-        let knot_span_index = knots.find_knot_span_index(n, degree, u);
-        let basis = knots.basis_functions(knot_span_index, u, degree);
-
-        let ls = knot_span_index - degree;
-        let row_start = vec![T::zero(); ls];
-        let row_end = vec![T::zero(); ld - ls];
-        let e = [row_start, basis, row_end].concat();
-        // println!("e = {:?}", &e);
-        for j in 0..e.len() {
-            m_a[(i, j)] = e[j];
+    let mut m_a = DMatrix::<T>::zeros(count, count);
+    let mut rhs = vec![];
+    for (i, (u, tangent)) in us.iter().zip(tangents.iter()).enumerate() {
+        let span = knots.find_knot_span_index(count - 1, degree, *u);
+        let derivs = knots.derivative_basis_functions(span, *u, degree, 1);
+        let row = rhs.len();
+        for (j, b) in derivs[0].iter().enumerate() {
+            m_a[(row, span - degree + j)] = *b;
+        }
+        rhs.push(points[i].clone());
+
+        if let Some(tangent) = tangent {
+            let norm = tangent.norm();
+            anyhow::ensure!(norm > T::default_epsilon(), "The tangent must not be zero");
+            let row = rhs.len();
+            for (j, b) in derivs[1].iter().enumerate() {
+                m_a[(row, span - degree + j)] = *b;
+            }
+            rhs.push(*tangent * (total / norm));
         }
     }
 
-    let control_points = try_solve_interpolation(m_a, points, homogeneous)?;
+    let control_points = try_solve_interpolation(m_a, &rhs, homogeneous)?;
 
     Ok((control_points, knots))
 }
@@ -2244,6 +2901,308 @@ pub fn try_periodic_interpolate_control_points<T: FloatingPoint>(
     Ok((control_points, knots_vec))
 }
 
+/// Compute the control points & knot vector of the curve approximating the points within the tolerance
+/// The first & last points are interpolated, and the optional tangents fix the directions at the ends.
+/// The magnitudes of the tangents are normalized to the total chord length of the points.
+pub fn try_approximate_control_points<T: FloatingPoint>(
+    points: &[DVector<T>],
+    degree: usize,
+    tolerance: T,
+    start_tangent: Option<&DVector<T>>,
+    end_tangent: Option<&DVector<T>>,
+    homogeneous: bool,
+) -> anyhow::Result<(Vec<DVector<T>>, KnotVector<T>)> {
+    anyhow::ensure!(degree >= 1, "The degree must be at least 1");
+    anyhow::ensure!(
+        tolerance > T::zero(),
+        "Tolerance must be positive, but got {}",
+        tolerance
+    );
+    anyhow::ensure!(points.len() >= 2, "Too few points for curve");
+
+    // chord length parameterization
+    let mut us = vec![T::zero()];
+    for i in 1..points.len() {
+        let last = us[i - 1];
+        us.push(last + (&points[i] - &points[i - 1]).norm());
+    }
+    let total = us[us.len() - 1];
+    anyhow::ensure!(total > T::default_epsilon(), "The points are coincident");
+    us.iter_mut().for_each(|u| *u /= total);
+
+    let tangent = |tangent: Option<&DVector<T>>| -> anyhow::Result<Option<DVector<T>>> {
+        tangent
+            .map(|t| {
+                let norm = t.norm();
+                anyhow::ensure!(norm > T::default_epsilon(), "The tangent must not be zero");
+                Ok(t * (total / norm))
+            })
+            .transpose()
+    };
+    let (start_tangent, end_tangent) = (tangent(start_tangent)?, tangent(end_tangent)?);
+    try_approximate_control_points_at(
+        points,
+        &us,
+        degree,
+        tolerance,
+        start_tangent.as_ref(),
+        end_tangent.as_ref(),
+        homogeneous,
+    )
+}
+
+/// Compute the control points & knot vector of the curve approximating the points at the parameters in [0, 1] within the tolerance
+/// The tangents are the derivatives of the curve at the ends with respect to the normalized parameter.
+/// Knots are inserted adaptively into the spans of the points deviating more than the tolerance.
+fn try_approximate_control_points_at<T: FloatingPoint>(
+    points: &[DVector<T>],
+    us: &[T],
+    degree: usize,
+    tolerance: T,
+    start_tangent: Option<&DVector<T>>,
+    end_tangent: Option<&DVector<T>>,
+    homogeneous: bool,
+) -> anyhow::Result<(Vec<DVector<T>>, KnotVector<T>)> {
+    let constraints = start_tangent.iter().count() + end_tangent.iter().count();
+
+    let max_count = points.len() + constraints;
+    anyhow::ensure!(max_count > degree, "Too few points for curve");
+
+    // start from the fewest control points satisfying the degree & the constraints
+    let count = (degree + 1).max(2 + constraints);
+    let mut inner = (1..(count - degree))
+        .map(|j| T::from_usize(j).unwrap() / T::from_usize(count - degree).unwrap())
+        .collect_vec();
+
+    loop {
+        let count = degree + 1 + inner.len();
+        let knots = KnotVector::new(
+            std::iter::repeat_n(T::zero(), degree + 1)
+                .chain(inner.iter().copied())
+                .chain(std::iter::repeat_n(T::one(), degree + 1))
+                .collect(),
+        );
+
+        let mut m = DMatrix::<T>::zeros(us.len(), count);
+        us.iter().enumerate().for_each(|(i, u)| {
+            let span = knots.find_knot_span_index(count - 1, degree, *u);
+            let basis = knots.basis_functions(span, *u, degree);
+            basis.into_iter().enumerate().for_each(|(j, b)| {
+                m[(i, span - degree + j)] = b;
+            });
+        });
+
+        let control_points =
+            try_fit_constrained(&m, points, &knots, degree, start_tangent, end_tangent)?;
+
+        let errors = (0..points.len())
+            .map(|i| {
+                let c = control_points
+                    .iter()
+                    .enumerate()
+                    .fold(DVector::zeros(points[i].len()), |acc, (j, p)| {
+                        acc + p * m[(i, j)]
+                    });
+                (c - &points[i]).norm()
+            })
+            .collect_vec();
+
+        let done = errors.iter().all(|e| *e <= tolerance);
+        if done || count >= max_count {
+            anyhow::ensure!(
+                done,
+                "Failed to approximate the points within the tolerance {}",
+                tolerance
+            );
+            let control_points = control_points
+                .into_iter()
+                .map(|p| if homogeneous { p.push(T::one()) } else { p })
+                .collect();
+            return Ok((control_points, knots));
+        }
+
+        // split the span of the worst point at the median of the parameters inside the span
+        let knot = errors
+            .iter()
+            .enumerate()
+            .filter(|(_, e)| **e > tolerance)
+            .sorted_by(|a, b| b.1.partial_cmp(a.1).unwrap_or(std::cmp::Ordering::Equal))
+            .find_map(|(i, _)| {
+                let span = knots.find_knot_span_index(count - 1, degree, us[i]);
+                let (lo, hi) = (knots[span], knots[span + 1]);
+                let inside = us.iter().filter(|u| lo < **u && **u < hi).collect_vec();
+                let n = inside.len();
+                (n >= 2).then(|| (*inside[n / 2 - 1] + *inside[n / 2]) / T::from_f64(2.).unwrap())
+            })
+            .ok_or(anyhow::anyhow!(
+                "Failed to approximate the points within the tolerance {}",
+                tolerance
+            ))?;
+        let index = inner.iter().position(|k| *k > knot).unwrap_or(inner.len());
+        inner.insert(index, knot);
+    }
+}
+
+/// Fit the control points to the points in the least squares sense by the matrix of basis functions
+/// with the end control points fixed to the end points & the optional tangents
+fn try_fit_constrained<T: FloatingPoint>(
+    m: &DMatrix<T>,
+    points: &[DVector<T>],
+    knots: &KnotVector<T>,
+    degree: usize,
+    start_tangent: Option<&DVector<T>>,
+    end_tangent: Option<&DVector<T>>,
+) -> anyhow::Result<Vec<DVector<T>>> {
+    let (rows, count) = m.shape();
+    let dim = points[0].len();
+    let p = T::from_usize(degree).unwrap();
+
+    let mut fixed: Vec<(usize, DVector<T>)> = vec![
+        (0, points[0].clone()),
+        (count - 1, points[rows - 1].clone()),
+    ];
+    // C'(0) = p / u_{p+1} (P1 - P0), C'(1) = p / (1 - u_n) (P_n - P_{n-1})
+    if let Some(t) = start_tangent {
+        fixed.push((1, &points[0] + t * (knots[degree + 1] / p)));
+    }
+    if let Some(t) = end_tangent {
+        let n = count - 1;
+        fixed.push((n - 1, &points[rows - 1] - t * ((T::one() - knots[n]) / p)));
+    }
+
+    let free = (0..count)
+        .filter(|j| fixed.iter().all(|(k, _)| k != j))
+        .collect_vec();
+    let mut control_points = vec![DVector::zeros(dim); count];
+    fixed
+        .iter()
+        .for_each(|(j, p)| control_points[*j] = p.clone());
+    if free.is_empty() {
+        return Ok(control_points);
+    }
+
+    let a = DMatrix::from_fn(rows, free.len(), |i, j| m[(i, free[j])]);
+    let rhs = DMatrix::from_fn(rows, dim, |i, k| {
+        fixed
+            .iter()
+            .fold(points[i][k], |acc, (j, p)| acc - m[(i, *j)] * p[k])
+    });
+    let solved = try_solve_least_squares(a, vec![rhs])?;
+    free.iter().enumerate().for_each(|(i, j)| {
+        control_points[*j] = solved[0].row(i).transpose();
+    });
+    Ok(control_points)
+}
+
+/// Reduce the degree of the Bezier curve keeping its end points & end tangents (if the target degree allows)
+/// and fitting the rest of the control points to the curve in the least squares sense
+fn try_reduce_bezier_degree<T: FloatingPoint, D>(
+    bezier: &NurbsCurve<T, D>,
+    target_degree: usize,
+) -> anyhow::Result<NurbsCurve<T, D>>
+where
+    D: DimName,
+    DefaultAllocator: Allocator<D>,
+{
+    let degree = bezier.degree();
+    let (start, end) = bezier.knots_domain();
+    let control_points =
+        DMatrix::from_fn(degree + 1, D::dim(), |i, k| bezier.control_points()[i][k]);
+
+    let bezier_knots = |degree: usize| {
+        KnotVector::new(
+            std::iter::repeat_n(T::zero(), degree + 1)
+                .chain(std::iter::repeat_n(T::one(), degree + 1))
+                .collect(),
+        )
+    };
+    let n = DEGREE_REDUCTION_SAMPLES * (degree + 1);
+    let samples = (0..=n)
+        .map(|i| T::from_usize(i).unwrap() / T::from_usize(n).unwrap())
+        .collect_vec();
+    let solved = try_reduce_bezier_control_points(
+        basis_matrix(&bezier_knots(degree), degree, degree + 1, &samples),
+        basis_matrix(
+            &bezier_knots(target_degree),
+            target_degree,
+            target_degree + 1,
+            &samples,
+        ),
+        control_points,
+    )?;
+
+    Ok(NurbsCurve::new_unchecked(
+        target_degree,
+        (0..solved.nrows())
+            .map(|i| OPoint::from_slice(solved.row(i).transpose().as_slice()))
+            .collect(),
+        KnotVector::new(
+            std::iter::repeat_n(start, target_degree + 1)
+                .chain(std::iter::repeat_n(end, target_degree + 1))
+                .collect(),
+        ),
+    ))
+}
+
+/// Compute the control points of the Bezier curve of the reduced degree
+/// fitting the values of the original basis functions & control points at the samples
+fn try_reduce_bezier_control_points<T: FloatingPoint>(
+    original: DMatrix<T>,
+    reduced: DMatrix<T>,
+    control_points: DMatrix<T>,
+) -> anyhow::Result<DMatrix<T>> {
+    let (p, q) = (original.ncols() - 1, reduced.ncols() - 1);
+    let ratio = T::from_usize(p).unwrap() / T::from_usize(q).unwrap();
+    let row = |i: usize| control_points.row(i).into_owned();
+
+    // q (Q1 - Q0) = p (P1 - P0) keeps the end tangents
+    let mut fixed = vec![(0, row(0)), (q, row(p))];
+    if q >= 3 {
+        fixed.push((1, row(0) + (row(1) - row(0)) * ratio));
+        fixed.push((q - 1, row(p) - (row(p) - row(p - 1)) * ratio));
+    }
+    let free = (0..=q)
+        .filter(|j| fixed.iter().all(|(k, _)| k != j))
+        .collect_vec();
+
+    let mut solved = DMatrix::zeros(q + 1, control_points.ncols());
+    fixed.iter().for_each(|(j, r)| solved.set_row(*j, r));
+    if !free.is_empty() {
+        let values = &original * &control_points;
+        let rhs = DMatrix::from_fn(values.nrows(), values.ncols(), |i, k| {
+            fixed
+                .iter()
+                .fold(values[(i, k)], |acc, (j, r)| acc - reduced[(i, *j)] * r[k])
+        });
+        let a = DMatrix::from_fn(reduced.nrows(), free.len(), |i, j| reduced[(i, free[j])]);
+        let x = try_solve_least_squares(a, vec![rhs])?;
+        free.iter().enumerate().for_each(|(i, j)| {
+            solved.set_row(*j, &x[0].row(i));
+        });
+    }
+    Ok(solved)
+}
+
+/// Measure the maximum distance between the points of the curves at the same parameters
+/// sampled in each knot span of the first curve
+fn deviation<T: FloatingPoint, D>(a: &NurbsCurve<T, D>, b: &NurbsCurve<T, D>) -> T
+where
+    D: DimName + DimNameSub<U1>,
+    DefaultAllocator: Allocator<D>,
+    DefaultAllocator: Allocator<DimNameDiff<D, U1>>,
+{
+    let n = DEGREE_REDUCTION_SAMPLES * (a.degree() + 1);
+    let div = T::from_usize(n).unwrap();
+    a.knots()
+        .iter()
+        .copied()
+        .dedup()
+        .tuple_windows()
+        .flat_map(|(s, e)| (0..=n).map(move |i| s + (e - s) * T::from_usize(i).unwrap() / div))
+        .map(|t| (a.point_at(t) - b.point_at(t)).norm())
+        .fold(T::zero(), |acc, d| acc.max(d))
+}
+
 fn try_solve_interpolation<T: FloatingPoint>(
     m_a: DMatrix<T>,
     points: &[DVector<T>],
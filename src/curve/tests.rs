@@ -1,12 +1,13 @@
-use nalgebra::{Point2, Rotation2, Translation2};
+use approx::assert_relative_eq;
+use nalgebra::{DVector, Point2, Point3, Rotation2, Translation2, Vector2, Vector3};
 
 use crate::{
-    curve::NurbsCurve2D,
+    curve::{CurveInterpolationOption, NurbsCurve2D, NurbsCurve3D},
     misc::Transformable,
     prelude::{CurveIntersectionSolverOptions, Intersects},
 };
 
-use super::KnotStyle;
+use super::{CurveEnd, ExtendType, KnotStyle};
 
 const OPTIONS: CurveIntersectionSolverOptions<f64> = CurveIntersectionSolverOptions {
     minimum_distance: 1e-4,
@@ -84,3 +85,340 @@ fn problem_case2() {
     let intersections = subject.find_intersection(&clip, Some(OPTIONS)).unwrap();
     assert_eq!(intersections.len(), 2);
 }
+
+#[test]
+fn elevate_degree_of_multi_span_curve() {
+    let curve = NurbsCurve2D::<f64>::polyline(
+        &[
+            Point2::new(0., 0.),
+            Point2::new(1., 0.),
+            Point2::new(1., 1.),
+            Point2::new(2., 1.),
+            Point2::new(2., 3.),
+            Point2::new(0., 2.),
+        ],
+        false,
+    );
+    let elevated = curve.try_elevate_degree(3).unwrap();
+    assert_eq!(elevated.degree(), 3);
+    assert_eq!(
+        elevated.knots().len(),
+        elevated.control_points().len() + elevated.degree() + 1
+    );
+    assert_eq!(elevated.knots_domain(), curve.knots_domain());
+
+    let (start, end) = curve.knots_domain();
+    for i in 0..=50 {
+        let t = start + (end - start) * i as f64 / 50.;
+        assert_relative_eq!(curve.point_at(t), elevated.point_at(t), epsilon = 1e-8);
+    }
+}
+
+#[test]
+fn rotation_minimizing_frames_close_up_on_closed_curve() {
+    // non-planar closed curve whose rotation-minimizing frames twist over a loop
+    let points = (0..12)
+        .map(|i| {
+            let t = i as f64 / 12. * std::f64::consts::TAU;
+            Point3::new(t.cos() * 2., t.sin() * 2., (t * 2.).sin())
+        })
+        .collect::<Vec<_>>();
+    let curve =
+        NurbsCurve3D::<f64>::try_periodic_interpolate(&points, 3, KnotStyle::Centripetal).unwrap();
+    assert!(curve.is_closed());
+
+    let (start, end) = curve.knots_domain();
+    let parameters = (0..=64)
+        .map(|i| start + (end - start) * i as f64 / 64.)
+        .collect::<Vec<_>>();
+    let frames = curve.compute_rotation_minimizing_frames(&parameters, Some(Vector3::z()));
+    let (first, last) = (&frames[0], &frames[frames.len() - 1]);
+    assert_relative_eq!(first.normal(), last.normal(), epsilon = 1e-6);
+
+    frames.iter().for_each(|frame| {
+        assert_relative_eq!(frame.normal().norm(), 1., epsilon = 1e-8);
+        assert_relative_eq!(frame.tangent().dot(frame.normal()), 0., epsilon = 1e-8);
+    });
+
+    // consecutive frames rotate only slightly
+    frames.windows(2).for_each(|w| {
+        assert!(w[0].normal().dot(w[1].normal()) > 0.9);
+    });
+}
+
+#[test]
+fn approximate_helix_within_tolerances() {
+    let points = (0..=200)
+        .map(|i| {
+            let t = i as f64 / 200. * std::f64::consts::TAU * 2.;
+            let noise = ((i * 13) % 7) as f64 / 7. * 1e-4;
+            Point3::new(t.cos(), t.sin() + noise, t * 0.2)
+        })
+        .collect::<Vec<_>>();
+
+    let counts = [1e-1, 1e-2, 1e-3].map(|tolerance| {
+        let curve = NurbsCurve3D::<f64>::try_approximate(&points, 3, tolerance).unwrap();
+        let (start, end) = curve.knots_domain();
+        assert_relative_eq!(curve.point_at(start), points[0], epsilon = 1e-8);
+        assert_relative_eq!(
+            curve.point_at(end),
+            points[points.len() - 1],
+            epsilon = 1e-8
+        );
+        points.iter().for_each(|p| {
+            let t = curve.find_closest_parameter(p).unwrap();
+            assert!((curve.point_at(t) - p).norm() <= tolerance);
+        });
+        curve.control_points().len()
+    });
+
+    // tighter tolerances need more control points, but far fewer than the points
+    assert!(counts[0] < counts[1] && counts[1] < counts[2]);
+    assert!(counts[2] < points.len() / 4);
+}
+
+#[test]
+fn interpolate_with_knot_styles_and_tangents() {
+    let points = [
+        Point2::new(0., 0.),
+        Point2::new(0.5, 1.),
+        Point2::new(3., 1.5),
+        Point2::new(3.5, -1.),
+        Point2::new(5., 0.),
+    ];
+
+    // the default option reproduces the plain interpolation
+    let plain = NurbsCurve2D::<f64>::try_interpolate(&points, 3).unwrap();
+    let default =
+        NurbsCurve2D::try_interpolate_with_option(&points, 3, CurveInterpolationOption::default())
+            .unwrap();
+    assert_eq!(plain, default);
+
+    for knot_style in [
+        KnotStyle::Uniform,
+        KnotStyle::Chordal,
+        KnotStyle::Centripetal,
+    ] {
+        let option = CurveInterpolationOption::default()
+            .with_knot_style(knot_style)
+            .with_start_tangent(Vector2::new(1., 1.))
+            .with_end_tangent(Vector2::x());
+        let curve = NurbsCurve2D::try_interpolate_with_option(&points, 3, option).unwrap();
+        assert_eq!(curve.control_points().len(), points.len() + 2);
+
+        let parameters = knot_style
+            .normalized_parameters(
+                &points
+                    .iter()
+                    .map(|p| DVector::from_column_slice(p.coords.as_slice()))
+                    .collect::<Vec<_>>(),
+            )
+            .unwrap();
+        for (p, u) in points.iter().zip(parameters) {
+            assert_relative_eq!(curve.point_at(u), p, epsilon = 1e-8);
+        }
+
+        let (start, end) = curve.knots_domain();
+        assert_relative_eq!(
+            curve.tangent_at(start).normalize(),
+            Vector2::new(1., 1.).normalize(),
+            epsilon = 1e-8
+        );
+        assert_relative_eq!(
+            curve.tangent_at(end).normalize(),
+            Vector2::x(),
+            epsilon = 1e-8
+        );
+    }
+}
+
+#[test]
+fn catmull_rom_matches_uniform_formula() {
+    let points = [
+        Point2::new(0., 0.),
+        Point2::new(1., 2.),
+        Point2::new(3., 2.5),
+        Point2::new(4., 0.),
+        Point2::new(6., 1.),
+    ];
+    let curve = NurbsCurve2D::<f64>::try_catmull_rom(&points, KnotStyle::Uniform).unwrap();
+    assert_eq!(curve.knots_domain(), (1., 3.));
+
+    // the classic uniform Catmull-Rom segment
+    let segment = |p: &[Point2<f64>], s: f64| {
+        let (s2, s3) = (s * s, s * s * s);
+        let c = [
+            -s3 + 2. * s2 - s,
+            3. * s3 - 5. * s2 + 2.,
+            -3. * s3 + 4. * s2 + s,
+            s3 - s2,
+        ];
+        Point2::from(
+            p.iter()
+                .zip(c)
+                .fold(Vector2::zeros(), |acc, (p, c)| acc + p.coords * c)
+                * 0.5,
+        )
+    };
+    for i in 0..=20 {
+        let s = i as f64 / 20.;
+        assert_relative_eq!(
+            curve.point_at(1. + s),
+            segment(&points[0..4], s),
+            epsilon = 1e-8
+        );
+        assert_relative_eq!(
+            curve.point_at(2. + s),
+            segment(&points[1..5], s),
+            epsilon = 1e-8
+        );
+    }
+
+    // tangent continuity at the joint
+    assert_relative_eq!(
+        curve.tangent_at(2. - 1e-9),
+        curve.tangent_at(2. + 1e-9),
+        epsilon = 1e-6
+    );
+}
+
+#[test]
+fn remove_multiple_knot() {
+    let curve = NurbsCurve2D::<f64>::try_interpolate(
+        &[
+            Point2::new(0., 0.),
+            Point2::new(1., 1.),
+            Point2::new(2., 0.),
+            Point2::new(3., 1.),
+            Point2::new(4., 2.),
+        ],
+        3,
+    )
+    .unwrap();
+
+    for times in 1..=3 {
+        let mut refined = curve.clone();
+        refined.try_refine_knot(vec![0.3; times]).unwrap();
+        let removed = refined.try_remove_knot(0.3, Some(1e-8)).unwrap();
+        assert_eq!(removed, times);
+        assert_eq!(refined.knots(), curve.knots());
+        for i in 0..=20 {
+            let t = i as f64 / 20.;
+            assert_relative_eq!(refined.point_at(t), curve.point_at(t), epsilon = 1e-8);
+        }
+    }
+}
+
+#[test]
+fn reduce_elevated_circle_degree() {
+    let circle =
+        NurbsCurve2D::<f64>::try_circle(&Point2::origin(), &Vector2::x(), &Vector2::y(), 1.)
+            .unwrap();
+    let elevated = circle.try_elevate_degree(5).unwrap();
+    let (reduced, error) = elevated.try_reduce_degree(2, 1e-8).unwrap();
+    assert_eq!(reduced.degree(), 2);
+    assert!(error <= 1e-8);
+
+    // the redundant knots introduced by the elevation are removed
+    assert_eq!(
+        reduced.control_points().len(),
+        circle.control_points().len()
+    );
+    let (start, end) = circle.knots_domain();
+    for i in 0..=32 {
+        let t = start + (end - start) * i as f64 / 32.;
+        assert_relative_eq!(reduced.point_at(t), circle.point_at(t), epsilon = 1e-8);
+    }
+}
+
+#[test]
+fn reparameterize_polyline_and_spline_by_arc_length() {
+    let tolerance = 1e-4;
+
+    // the arc length parameterization of a polyline places the knots at the corners
+    let polyline = NurbsCurve2D::<f64>::polyline(
+        &[
+            Point2::new(0., 0.),
+            Point2::new(3., 0.),
+            Point2::new(3., 1.),
+        ],
+        false,
+    );
+    let reparameterized = polyline
+        .try_reparameterize_by_arc_length(tolerance)
+        .unwrap();
+    assert_eq!(reparameterized.knots_domain(), (0., 4.));
+    assert_relative_eq!(
+        reparameterized.point_at(3.5),
+        Point2::new(3., 0.5),
+        epsilon = 1e-8
+    );
+
+    let points = vec![
+        Point2::new(0., 0.),
+        Point2::new(1., 2.),
+        Point2::new(2., 0.5),
+        Point2::new(4., 1.5),
+        Point2::new(5., 0.),
+    ];
+    let curve = NurbsCurve2D::<f64>::try_interpolate(&points, 3).unwrap();
+    let reparameterized = curve.try_reparameterize_by_arc_length(tolerance).unwrap();
+
+    // the arc lengths measured along the dense polyline on the curve
+    let (start, end) = curve.knots_domain();
+    let samples = (0..=100000)
+        .map(|i| curve.point_at(start + (end - start) * i as f64 / 100000.))
+        .collect::<Vec<_>>();
+    let mut length = 0.;
+    let lengths = std::iter::once(0.)
+        .chain(samples.windows(2).map(|w| {
+            length += (w[1] - w[0]).norm();
+            length
+        }))
+        .collect::<Vec<_>>();
+
+    let (start, end) = reparameterized.knots_domain();
+    assert_eq!(start, 0.);
+    assert_relative_eq!(end, length, epsilon = tolerance);
+
+    for i in 0..=200 {
+        let s = length * i as f64 / 200.;
+        let j = lengths
+            .partition_point(|l| *l < s)
+            .clamp(1, samples.len() - 1);
+        let r = (s - lengths[j - 1]) / (lengths[j] - lengths[j - 1]);
+        let p = samples[j - 1] + (samples[j] - samples[j - 1]) * r;
+        assert!((reparameterized.point_at(s) - p).norm() <= tolerance);
+    }
+}
+
+#[test]
+fn extend_spline_naturally() {
+    let points = [
+        Point2::new(0., 0.),
+        Point2::new(1., 1.),
+        Point2::new(2., 0.5),
+        Point2::new(3., 1.5),
+    ];
+    let curve = NurbsCurve2D::<f64>::try_interpolate(&points, 3).unwrap();
+    let length = curve.try_length().unwrap();
+
+    for end in [CurveEnd::Start, CurveEnd::End] {
+        let mut extended = curve.try_extend(end, 0.5, ExtendType::Natural).unwrap();
+        assert_relative_eq!(extended.try_length().unwrap(), length + 0.5, epsilon = 1e-4);
+
+        // the original parameters are kept
+        let (start, end) = curve.knots_domain();
+        for i in 0..=10 {
+            let t = start + (end - start) * i as f64 / 10.;
+            assert_relative_eq!(extended.point_at(t), curve.point_at(t), epsilon = 1e-8);
+        }
+
+        // the extension continues the polynomial, so the knots at the joint are removable
+        extended.try_reduce_knots(Some(1e-8)).unwrap();
+        assert_eq!(
+            extended.control_points().len(),
+            curve.control_points().len()
+        );
+    }
+}
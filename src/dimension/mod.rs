@@ -1,8 +1,12 @@
 use nalgebra::{
-    allocator::Allocator, DefaultAllocator, DimName, DimNameAdd, DimNameSum, OPoint, U1,
+    allocator::Allocator, DefaultAllocator, DimName, DimNameAdd, DimNameSum, OPoint, Point3, U1,
 };
 
-use crate::{curve::NurbsCurve, misc::FloatingPoint, region::CompoundCurve};
+use crate::{
+    curve::{NurbsCurve, NurbsCurve2D, NurbsCurve3D},
+    misc::FloatingPoint,
+    region::CompoundCurve,
+};
 
 /// Elevate the dimension of the geometry
 pub trait ElevateDimension {
@@ -76,3 +80,27 @@ where
         CompoundCurve::new_unchecked(spans)
     }
 }
+
+/// Try to flatten the 3D curve lying on the xy plane into the 2D curve by dropping the z coordinate
+/// Returns an error if the curve is away from the xy plane over the tolerance
+pub(crate) fn try_flatten_curve<T: FloatingPoint>(
+    curve: &NurbsCurve3D<T>,
+    tolerance: T,
+) -> anyhow::Result<NurbsCurve2D<T>> {
+    let control_points = curve
+        .control_points()
+        .iter()
+        .map(|p| {
+            anyhow::ensure!(
+                (p.z / p.w).abs() < tolerance,
+                "The curve is not lying on the plane"
+            );
+            Ok(Point3::new(p.x, p.y, p.w))
+        })
+        .collect::<anyhow::Result<Vec<_>>>()?;
+    Ok(NurbsCurve2D::new_unchecked(
+        curve.degree(),
+        control_points,
+        curve.knots().clone(),
+    ))
+}
@@ -166,3 +166,54 @@ impl<T: FloatingPoint> FilletDistanceOption<T> {
         self.distance
     }
 }
+
+/// Fillet two separate curves with a given radius
+/// The hint parameters on both curves tell where the fillet should be created,
+/// otherwise the fillet is searched around the closest points of the curves.
+/// The tolerance is the allowed distance between the arc centers found from both curves,
+/// which defaults to the radius times 1e-10.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FilletCurvesOption<T: FloatingPoint> {
+    radius: T,
+    hint: Option<(T, T)>,
+    tolerance: Option<T>,
+}
+
+impl<T: FloatingPoint> FilletCurvesOption<T> {
+    pub fn new(radius: T) -> Self {
+        Self {
+            radius,
+            hint: None,
+            tolerance: None,
+        }
+    }
+
+    pub fn radius(&self) -> T {
+        self.radius
+    }
+
+    pub fn hint(&self) -> Option<(T, T)> {
+        self.hint
+    }
+
+    pub fn tolerance(&self) -> T {
+        self.tolerance
+            .unwrap_or(self.radius * T::from_f64(1e-10).unwrap())
+    }
+
+    pub fn with_radius(mut self, radius: T) -> Self {
+        self.radius = radius;
+        self
+    }
+
+    /// Set the parameters on the first & the second curve near the fillet
+    pub fn with_hint(mut self, a: T, b: T) -> Self {
+        self.hint = Some((a, b));
+        self
+    }
+
+    pub fn with_tolerance(mut self, tolerance: T) -> Self {
+        self.tolerance = Some(tolerance);
+        self
+    }
+}
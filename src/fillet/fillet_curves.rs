@@ -0,0 +1,295 @@
+use itertools::Itertools;
+use nalgebra::{Matrix2, Point2, Vector2};
+
+use crate::{
+    curve::{CurveEnd, ExtendType, NurbsCurve2D},
+    fillet::{Fillet, FilletCurvesOption},
+    misc::FloatingPoint,
+    split::Split,
+};
+
+/// Maximum number of Newton iterations to find the contact points of the fillet arc
+const MAX_CONTACT_ITERS: usize = 32;
+
+/// Number of samples per control point to find the closest points of the curves
+const SAMPLES_PER_CONTROL_POINT: usize = 8;
+
+/// A result of filleting two curves
+/// Holds the fillet arc & the input curves trimmed at the contact points of the arc.
+#[derive(Clone, Debug)]
+pub struct CurvesFillet<T: FloatingPoint> {
+    arc: NurbsCurve2D<T>,
+    a: NurbsCurve2D<T>,
+    b: NurbsCurve2D<T>,
+}
+
+impl<T: FloatingPoint> CurvesFillet<T> {
+    /// Get the fillet arc from the first curve to the second curve
+    pub fn arc(&self) -> &NurbsCurve2D<T> {
+        &self.arc
+    }
+
+    /// Get the first curve trimmed from its start to the contact point
+    pub fn a(&self) -> &NurbsCurve2D<T> {
+        &self.a
+    }
+
+    /// Get the second curve trimmed from the contact point to its end
+    pub fn b(&self) -> &NurbsCurve2D<T> {
+        &self.b
+    }
+
+    pub fn into_tuple(self) -> (NurbsCurve2D<T>, NurbsCurve2D<T>, NurbsCurve2D<T>) {
+        (self.arc, self.a, self.b)
+    }
+}
+
+impl<T: FloatingPoint> Fillet<FilletCurvesOption<T>> for (&NurbsCurve2D<T>, &NurbsCurve2D<T>) {
+    type Output = anyhow::Result<CurvesFillet<T>>;
+
+    /// Fillet the pair of curves with an arc of the given radius
+    /// The curves don't need to touch each other.
+    /// The arc is tangent to both curves and connects the end side of the first curve to the start side of the second curve,
+    /// so the first curve is kept from its start to the arc & the second curve is kept from the arc to its end.
+    /// Reverse the curves to choose the parts to keep.
+    /// The end of the first curve & the start of the second curve are extended along their tangents
+    /// by the gap between them plus the diameter of the arc, so that the arc can touch the curves beyond their ends.
+    /// # Example
+    /// ```
+    /// use curvo::prelude::*;
+    /// use nalgebra::Point2;
+    /// use approx::assert_relative_eq;
+    ///
+    /// // two lines which would meet at (1, 0) if extended
+    /// let a = NurbsCurve2D::polyline(&[Point2::new(-1., 0.), Point2::new(0.8, 0.)], false);
+    /// let b = NurbsCurve2D::polyline(&[Point2::new(1., 0.2), Point2::new(1., 2.)], false);
+    ///
+    /// let fillet = (&a, &b).fillet(FilletCurvesOption::new(0.5)).unwrap();
+    /// let arc = fillet.arc();
+    /// let (start, end) = arc.knots_domain();
+    /// assert_relative_eq!(arc.point_at(start), Point2::new(0.5, 0.), epsilon = 1e-8);
+    /// assert_relative_eq!(arc.point_at(end), Point2::new(1., 0.5), epsilon = 1e-8);
+    ///
+    /// let a = fillet.a();
+    /// assert_relative_eq!(a.point_at(a.knots_domain().1), Point2::new(0.5, 0.), epsilon = 1e-8);
+    /// let b = fillet.b();
+    /// assert_relative_eq!(b.point_at(b.knots_domain().0), Point2::new(1., 0.5), epsilon = 1e-8);
+    /// ```
+    fn fillet(&self, option: FilletCurvesOption<T>) -> Self::Output {
+        let (a, b) = *self;
+        let radius = option.radius();
+        anyhow::ensure!(
+            radius > T::zero(),
+            "Radius must be positive, but got {}",
+            radius
+        );
+
+        let (a_start, a_end) = a.knots_domain();
+        let (b_start, b_end) = b.knots_domain();
+        let gap = (a.point_at(a_end) - b.point_at(b_start)).norm();
+        let length = gap + radius * T::from_usize(2).unwrap();
+        let ea = a.try_extend(CurveEnd::End, length, ExtendType::Line)?;
+        let eb = b.try_extend(CurveEnd::Start, length, ExtendType::Line)?;
+
+        let initial = option
+            .hint()
+            .unwrap_or_else(|| find_closest_parameters(&ea, &eb));
+
+        // the arc turns left if the center is on the left side of the curves, otherwise turns right
+        let tolerance = option.tolerance();
+        let (ta, tb, side, center, sweep) = [T::one(), -T::one()]
+            .into_iter()
+            .filter_map(|side| {
+                let (ta, tb) =
+                    find_contact_parameters(&ea, &eb, initial, radius * side, tolerance)?;
+                let (center, _) = offset_point_at(&ea, ta, radius * side)?;
+                let (pa, pb) = (ea.point_at(ta), eb.point_at(tb));
+                let sweep = sweep_angle(&(pa - center), &(pb - center), side);
+                Some((ta, tb, side, center, sweep))
+            })
+            .min_by(|x, y| x.4.partial_cmp(&y.4).unwrap_or(std::cmp::Ordering::Equal))
+            .ok_or(anyhow::anyhow!(
+                "Failed to find the fillet arc of the radius {}",
+                radius
+            ))?;
+
+        let eps = T::from_f64(1e-8).unwrap();
+        anyhow::ensure!(
+            ta - a_start > eps && b_end - tb > eps,
+            "The fillet arc of the radius {} consumes the whole curve",
+            radius
+        );
+
+        let x_axis = ea.point_at(ta) - center;
+        let y_axis = Vector2::new(-x_axis.y, x_axis.x) * side;
+        let arc = NurbsCurve2D::try_arc(&center, &x_axis, &y_axis, radius, T::zero(), sweep)?;
+
+        // trim the curves at the contact points, or extend them if the contact points lie beyond the ends
+        let a = if (ta - a_end).abs() > eps {
+            ea.try_split(ta)?.0
+        } else {
+            a.clone()
+        };
+        let b = if (tb - b_start).abs() > eps {
+            eb.try_split(tb)?.1
+        } else {
+            b.clone()
+        };
+
+        Ok(CurvesFillet { arc, a, b })
+    }
+}
+
+/// Find the parameters of the closest sampled points between the curves
+fn find_closest_parameters<T: FloatingPoint>(a: &NurbsCurve2D<T>, b: &NurbsCurve2D<T>) -> (T, T) {
+    let sample = |curve: &NurbsCurve2D<T>| {
+        let (start, end) = curve.knots_domain();
+        let n = curve.control_points().len() * SAMPLES_PER_CONTROL_POINT;
+        curve.sample_regular_range_with_parameter(start, end, n)
+    };
+    let (sa, sb) = (sample(a), sample(b));
+    sa.iter()
+        .cartesian_product(sb.iter())
+        .map(|((ta, pa), (tb, pb))| (*ta, *tb, (pa - pb).norm_squared()))
+        .min_by(|x, y| x.2.partial_cmp(&y.2).unwrap_or(std::cmp::Ordering::Equal))
+        .map(|(ta, tb, _)| (ta, tb))
+        .unwrap_or((a.knots_domain().1, b.knots_domain().0))
+}
+
+/// Find the parameters on both curves whose points offset along the left normals by the distance coincide
+/// by Newton's method
+fn find_contact_parameters<T: FloatingPoint>(
+    a: &NurbsCurve2D<T>,
+    b: &NurbsCurve2D<T>,
+    initial: (T, T),
+    distance: T,
+    tolerance: T,
+) -> Option<(T, T)> {
+    let (a_domain, b_domain) = (a.knots_domain(), b.knots_domain());
+    let mut t = Vector2::new(
+        initial.0.clamp(a_domain.0, a_domain.1),
+        initial.1.clamp(b_domain.0, b_domain.1),
+    );
+    for _ in 0..MAX_CONTACT_ITERS {
+        let (pa, da) = offset_point_at(a, t.x, distance)?;
+        let (pb, db) = offset_point_at(b, t.y, distance)?;
+        let f = pa - pb;
+        if f.norm() < tolerance {
+            return Some((t.x, t.y));
+        }
+        let j = Matrix2::from_columns(&[da, -db]);
+        let delta = j.lu().solve(&(-f))?;
+        t = Vector2::new(
+            (t.x + delta.x).clamp(a_domain.0, a_domain.1),
+            (t.y + delta.y).clamp(b_domain.0, b_domain.1),
+        );
+    }
+
+    let (pa, _) = offset_point_at(a, t.x, distance)?;
+    let (pb, _) = offset_point_at(b, t.y, distance)?;
+    ((pa - pb).norm() < tolerance).then_some((t.x, t.y))
+}
+
+/// Evaluate the point offset along the left normal by the distance & its derivative at the parameter
+fn offset_point_at<T: FloatingPoint>(
+    curve: &NurbsCurve2D<T>,
+    t: T,
+    distance: T,
+) -> Option<(Point2<T>, Vector2<T>)> {
+    let derivs = curve.rational_derivatives(t, 2);
+    let (d1, d2) = (derivs[1], derivs[2]);
+    let length = d1.norm();
+    if length < T::default_epsilon() {
+        return None;
+    }
+    let tangent = d1 / length;
+    let dtangent = (d2 - tangent * tangent.dot(&d2)) / length;
+    let normal = Vector2::new(-tangent.y, tangent.x);
+    let dnormal = Vector2::new(-dtangent.y, dtangent.x);
+    Some((
+        Point2::from(derivs[0] + normal * distance),
+        d1 + dnormal * distance,
+    ))
+}
+
+/// Compute the angle swept from `from` to `to` in the direction of the side (counter-clockwise if positive)
+fn sweep_angle<T: FloatingPoint>(from: &Vector2<T>, to: &Vector2<T>, side: T) -> T {
+    let angle = from.perp(to).atan2(from.dot(to)) * side;
+    if angle < T::zero() {
+        angle + T::two_pi()
+    } else {
+        angle
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+    use nalgebra::{Point2, Vector2};
+
+    use crate::{
+        curve::NurbsCurve2D,
+        fillet::{Fillet, FilletCurvesOption},
+    };
+
+    #[test]
+    fn fillet_line_and_circle() {
+        let line =
+            NurbsCurve2D::<f64>::polyline(&[Point2::new(-3., 0.), Point2::new(3., 0.)], false);
+        let center = Point2::new(2., 0.5);
+        let circle = NurbsCurve2D::try_circle(&center, &Vector2::x(), &Vector2::y(), 1.).unwrap();
+
+        let radius = 0.25;
+        let fillet = (&line, &circle)
+            .fillet(FilletCurvesOption::new(radius))
+            .unwrap();
+        let arc = fillet.arc();
+        let (start, end) = arc.knots_domain();
+        let (p0, p1) = (arc.point_at(start), arc.point_at(end));
+
+        // the arc touches the line & the circle
+        assert_relative_eq!(p0.y, 0., epsilon = 1e-8);
+        assert_relative_eq!((p1 - center).norm(), 1., epsilon = 1e-8);
+
+        // the arc continues the line & leads into the circle
+        let a = fillet.a();
+        let a_end = a.knots_domain().1;
+        assert_relative_eq!(a.point_at(a_end), p0, epsilon = 1e-8);
+        assert_relative_eq!(
+            arc.tangent_at(start).normalize(),
+            a.tangent_at(a_end).normalize(),
+            epsilon = 1e-8
+        );
+        let b = fillet.b();
+        let b_start = b.knots_domain().0;
+        assert_relative_eq!(b.point_at(b_start), p1, epsilon = 1e-8);
+        assert_relative_eq!(
+            arc.tangent_at(end).normalize(),
+            b.tangent_at(b_start).normalize(),
+            epsilon = 1e-8
+        );
+    }
+
+    #[test]
+    fn fillet_lines_stopping_short() {
+        // the contact points of the arc lie beyond the ends of both lines
+        let a = NurbsCurve2D::<f64>::polyline(&[Point2::new(-1., 0.), Point2::new(0.2, 0.)], false);
+        let b = NurbsCurve2D::polyline(&[Point2::new(1., 0.8), Point2::new(1., 2.)], false);
+
+        let fillet = (&a, &b).fillet(FilletCurvesOption::new(0.5)).unwrap();
+        let arc = fillet.arc();
+        let (start, end) = arc.knots_domain();
+        assert_relative_eq!(arc.point_at(start), Point2::new(0.5, 0.), epsilon = 1e-8);
+        assert_relative_eq!(arc.point_at(end), Point2::new(1., 0.5), epsilon = 1e-8);
+
+        // the lines are extended to the contact points
+        let a = fillet.a();
+        let (a_start, a_end) = a.knots_domain();
+        assert_relative_eq!(a.point_at(a_start), Point2::new(-1., 0.), epsilon = 1e-8);
+        assert_relative_eq!(a.point_at(a_end), Point2::new(0.5, 0.), epsilon = 1e-8);
+        let b = fillet.b();
+        let (b_start, b_end) = b.knots_domain();
+        assert_relative_eq!(b.point_at(b_start), Point2::new(1., 0.5), epsilon = 1e-8);
+        assert_relative_eq!(b.point_at(b_end), Point2::new(1., 2.), epsilon = 1e-8);
+    }
+}
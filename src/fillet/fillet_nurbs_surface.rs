@@ -0,0 +1,444 @@
+use argmin::core::ArgminFloat;
+use itertools::Itertools;
+use nalgebra::{Matrix3x2, Point2, Point3, Vector2, Vector3};
+
+use crate::{
+    curve::{NurbsCurve2D, NurbsCurve3D},
+    fillet::{SurfaceBlendType, SurfaceFilletOption},
+    misc::{FloatingPoint, PolygonBoundary},
+    prelude::{Contains, Intersects},
+    region::CompoundCurve2D,
+    surface::{NurbsSurface3D, TrimmedSurface},
+    tessellation::Tessellation,
+};
+
+/// Maximum number of Gauss-Newton iterations to find the contact point of the ball
+const MAX_CONTACT_ITERS: usize = 16;
+
+/// Minimum & maximum number of the cross sections of the blend surface
+const MIN_SECTIONS: usize = 8;
+const MAX_SECTIONS: usize = 64;
+
+/// A result of blending two surfaces by a rolling ball
+/// Holds the blend surface & the supporting surfaces trimmed by the contact curves of the ball.
+#[derive(Clone, Debug)]
+pub struct SurfaceFillet<T: FloatingPoint> {
+    blend: NurbsSurface3D<T>,
+    a: TrimmedSurface<T>,
+    b: TrimmedSurface<T>,
+}
+
+impl<T: FloatingPoint> SurfaceFillet<T> {
+    /// Get the blend surface
+    /// The u direction goes across the blend from the first surface to the second one,
+    /// and the v direction goes along the path of the ball.
+    pub fn blend(&self) -> &NurbsSurface3D<T> {
+        &self.blend
+    }
+
+    /// Get the first surface trimmed by the contact curve
+    pub fn a(&self) -> &TrimmedSurface<T> {
+        &self.a
+    }
+
+    /// Get the second surface trimmed by the contact curve
+    pub fn b(&self) -> &TrimmedSurface<T> {
+        &self.b
+    }
+
+    pub fn into_tuple(self) -> (NurbsSurface3D<T>, TrimmedSurface<T>, TrimmedSurface<T>) {
+        (self.blend, self.a, self.b)
+    }
+}
+
+impl<T: FloatingPoint + ArgminFloat> NurbsSurface3D<T> {
+    /// Try to blend the surface & the other surface by a rolling ball of the radius
+    /// The ball rolls on the side to which the normals of both surfaces point,
+    /// so flip the surfaces to blend on the other side.
+    /// The path of the ball center is found as the intersection of the offset surfaces,
+    /// and a blend is created for each intersection curve.
+    /// # Example
+    /// ```
+    /// use curvo::prelude::*;
+    /// use nalgebra::{Point3, Vector3};
+    /// use approx::assert_relative_eq;
+    ///
+    /// // floor & wall meeting at x = -1, both facing the inside of the corner
+    /// let floor = NurbsSurface3D::plane(Point3::origin(), Vector3::x(), Vector3::y());
+    /// let wall = NurbsSurface3D::plane(Point3::new(-1., 0., 1.), Vector3::y(), Vector3::z());
+    ///
+    /// let fillets = floor.try_fillet(&wall, SurfaceFilletOption::new(0.25)).unwrap();
+    /// assert_eq!(fillets.len(), 1);
+    ///
+    /// // the blend is a quarter cylinder around the line x = -0.75, z = 0.25
+    /// let blend = fillets[0].blend();
+    /// let (u, v) = blend.knots_domain();
+    /// for i in 0..=4 {
+    ///     for j in 0..=4 {
+    ///         let s = u.0 + (u.1 - u.0) * i as f64 / 4.;
+    ///         let t = v.0 + (v.1 - v.0) * j as f64 / 4.;
+    ///         let p = blend.point_at(s, t);
+    ///         let d = (p.x + 0.75).hypot(p.z - 0.25);
+    ///         assert_relative_eq!(d, 0.25, epsilon = 1e-3);
+    ///     }
+    /// }
+    /// ```
+    pub fn try_fillet(
+        &self,
+        other: &Self,
+        option: SurfaceFilletOption<T>,
+    ) -> anyhow::Result<Vec<SurfaceFillet<T>>> {
+        let radius = option.radius();
+        anyhow::ensure!(
+            radius > T::zero(),
+            "Radius must be positive, but got {}",
+            radius
+        );
+
+        let oa = self.try_offset(radius, option.tolerance())?.into_surface();
+        let ob = other.try_offset(radius, option.tolerance())?.into_surface();
+        let paths = oa.find_intersection(&ob, Some(option.intersection().clone()))?;
+
+        paths
+            .iter()
+            .map(|path| {
+                let n = path
+                    .curve()
+                    .control_points()
+                    .len()
+                    .clamp(MIN_SECTIONS, MAX_SECTIONS);
+                let contacts = (0..n)
+                    .map(|i| {
+                        let r = T::from_usize(i).unwrap() / T::from_usize(n - 1).unwrap();
+                        let center = path.curve().point_at(lerp(path.curve().knots_domain(), r));
+                        let ua = path
+                            .a_curve()
+                            .point_at(lerp(path.a_curve().knots_domain(), r));
+                        let ub = path
+                            .b_curve()
+                            .point_at(lerp(path.b_curve().knots_domain(), r));
+                        let ua =
+                            try_find_contact(self, &center, radius, ua.coords, option.tolerance())?;
+                        let ub = try_find_contact(
+                            other,
+                            &center,
+                            radius,
+                            ub.coords,
+                            option.tolerance(),
+                        )?;
+                        Ok((center, ua, ub))
+                    })
+                    .collect::<anyhow::Result<Vec<_>>>()?;
+
+                let sections = contacts
+                    .iter()
+                    .map(|(center, ua, ub)| {
+                        let pa = self.point_at(ua.x, ua.y);
+                        let pb = other.point_at(ub.x, ub.y);
+                        try_blend_section(center, &pa, &pb, option.blend_type())
+                    })
+                    .collect::<anyhow::Result<Vec<_>>>()?;
+                let blend = NurbsSurface3D::try_loft(&sections, Some(3))?;
+
+                let ua = contacts
+                    .iter()
+                    .map(|(_, ua, _)| Point2::from(*ua))
+                    .collect_vec();
+                let ub = contacts
+                    .iter()
+                    .map(|(_, _, ub)| Point2::from(*ub))
+                    .collect_vec();
+                let (pa, pb) = contacts
+                    .get(n / 2)
+                    .map(|(_, ua, ub)| (self.point_at(ua.x, ua.y), other.point_at(ub.x, ub.y)))
+                    .ok_or(anyhow::anyhow!("No contact point found"))?;
+                let a = try_trim_support(self, &ua, &(pb - pa))?;
+                let b = try_trim_support(other, &ub, &(pa - pb))?;
+
+                Ok(SurfaceFillet { blend, a, b })
+            })
+            .collect()
+    }
+}
+
+/// Linear interpolation in the domain
+fn lerp<T: FloatingPoint>(domain: (T, T), r: T) -> T {
+    domain.0 + (domain.1 - domain.0) * r
+}
+
+/// Find the (u, v) parameter of the contact point of the ball by Gauss-Newton method,
+/// where the point offset along the normal by the radius coincides with the center of the ball
+/// Fails if the offset point does not reach the center within the tolerance.
+fn try_find_contact<T: FloatingPoint>(
+    surface: &NurbsSurface3D<T>,
+    center: &Point3<T>,
+    radius: T,
+    uv: Vector2<T>,
+    tolerance: T,
+) -> anyhow::Result<Vector2<T>> {
+    let ((u0, u1), (v0, v1)) = surface.knots_domain();
+    let residual_at = |uv: &Vector2<T>| -> anyhow::Result<(Vector3<T>, Matrix3x2<T>)> {
+        let d = surface.rational_derivatives(uv.x, uv.y, 1);
+        let (su, sv) = (d[1][0], d[0][1]);
+        let n = su.cross(&sv);
+        anyhow::ensure!(
+            n.norm_squared() > T::default_epsilon(),
+            "The surface is degenerate at the contact point"
+        );
+        let residual = center - (Point3::from(d[0][0]) + n.normalize() * radius);
+        Ok((residual, Matrix3x2::from_columns(&[su, sv])))
+    };
+
+    let mut uv = uv;
+    for _ in 0..MAX_CONTACT_ITERS {
+        let (residual, j) = residual_at(&uv)?;
+        let delta = (j.transpose() * j)
+            .lu()
+            .solve(&(j.transpose() * residual))
+            .ok_or(anyhow::anyhow!("Failed to solve the contact point"))?;
+        uv = Vector2::new(
+            (uv.x + delta.x).clamp(u0, u1),
+            (uv.y + delta.y).clamp(v0, v1),
+        );
+        if delta.norm() < T::default_epsilon() {
+            break;
+        }
+    }
+
+    let (residual, _) = residual_at(&uv)?;
+    anyhow::ensure!(
+        residual.norm() <= tolerance,
+        "The ball does not touch the surface: the contact point is off by {}",
+        residual.norm()
+    );
+    Ok(uv)
+}
+
+/// Create the cross section of the blend as a rational quadratic curve from `a` to `b`
+/// Fails if the contact points are opposite across the center, where the arc spans a half circle.
+fn try_blend_section<T: FloatingPoint>(
+    center: &Point3<T>,
+    a: &Point3<T>,
+    b: &Point3<T>,
+    blend_type: SurfaceBlendType,
+) -> anyhow::Result<NurbsCurve3D<T>> {
+    let half = T::from_f64(0.5).unwrap();
+    let (middle, weight) = match blend_type {
+        SurfaceBlendType::Round => {
+            let (da, db) = (a - center, b - center);
+            let radius = (da.norm() + db.norm()) * half;
+            let bisector = (da + db).normalize();
+            let cos = (da.angle(&db) * half).cos();
+            anyhow::ensure!(
+                cos > T::from_f64(1e-6).unwrap(),
+                "The contact points must not be opposite across the center of the ball"
+            );
+            (center + bisector * (radius / cos), cos)
+        }
+        SurfaceBlendType::Chamfer => (Point3::from((a.coords + b.coords) * half), T::one()),
+    };
+    Ok(NurbsCurve3D::new_unchecked(
+        2,
+        vec![
+            a.to_homogeneous().into(),
+            (middle.coords * weight).push(weight).into(),
+            b.to_homogeneous().into(),
+        ],
+        vec![
+            T::zero(),
+            T::zero(),
+            T::zero(),
+            T::one(),
+            T::one(),
+            T::one(),
+        ]
+        .into(),
+    ))
+}
+
+/// Trim the supporting surface by the contact curve in the (u, v) space
+/// The part of the surface on the side of `toward` (the direction to the other contact point) is removed.
+/// The contact curve is closed by the boundary of the domain on the remaining side.
+fn try_trim_support<T: FloatingPoint + ArgminFloat>(
+    surface: &NurbsSurface3D<T>,
+    contacts: &[Point2<T>],
+    toward: &Vector3<T>,
+) -> anyhow::Result<TrimmedSurface<T>> {
+    let curve = NurbsCurve2D::try_interpolate(contacts, 3.min(contacts.len() - 1))?;
+    let ((u0, u1), (v0, v1)) = surface.knots_domain();
+    let corners = [
+        Point2::new(u0, v0),
+        Point2::new(u1, v0),
+        Point2::new(u1, v1),
+        Point2::new(u0, v1),
+    ];
+
+    let (head, tail) = (contacts[0], contacts[contacts.len() - 1]);
+    let (sh, ph) = project_to_boundary(&corners, &head);
+    let (st, pt) = project_to_boundary(&corners, &tail);
+
+    // the point slightly moved from the contact curve toward the removed side
+    let mid = contacts[contacts.len() / 2];
+    let d = surface.rational_derivatives(mid.x, mid.y, 1);
+    let j = Matrix3x2::from_columns(&[d[1][0], d[0][1]]);
+    let dir = (j.transpose() * j)
+        .lu()
+        .solve(&(j.transpose() * toward))
+        .ok_or(anyhow::anyhow!("Failed to find the removed side"))?;
+    let step = (corners[2] - corners[0]).norm() * T::from_f64(1e-3).unwrap();
+    let removed = mid + dir.normalize() * step;
+
+    // walk along the boundary from the tail to the head counter-clockwise or clockwise
+    let eps = T::from_f64(1e-10).unwrap();
+    let candidates = [true, false]
+        .into_iter()
+        .map(|ccw| {
+            let walk = boundary_walk(&corners, st, sh, ccw);
+            let pts = std::iter::once(tail)
+                .chain(std::iter::once(pt))
+                .chain(walk)
+                .chain(std::iter::once(ph))
+                .chain(std::iter::once(head))
+                .dedup_by(|a, b| (a - b).norm() < eps)
+                .collect_vec();
+            CompoundCurve2D::new_unchecked_aligned(vec![
+                curve.clone(),
+                NurbsCurve2D::polyline(&pts, false),
+            ])
+        })
+        .collect_vec();
+
+    for exterior in candidates.into_iter() {
+        let polygon = PolygonBoundary::new(exterior.tessellate(None));
+        if !polygon.contains(&removed, ())? {
+            return Ok(TrimmedSurface::new(surface.clone(), Some(exterior), vec![]));
+        }
+    }
+    anyhow::bail!("Failed to trim the surface by the contact curve")
+}
+
+/// Project the point onto the boundary of the rectangle
+/// Returns the perimeter parameter in [0, 4) (the index of the edge + the fraction) & the projected point
+fn project_to_boundary<T: FloatingPoint>(
+    corners: &[Point2<T>; 4],
+    p: &Point2<T>,
+) -> (T, Point2<T>) {
+    (0..4)
+        .map(|i| {
+            let (a, b) = (corners[i], corners[(i + 1) % 4]);
+            let d = b - a;
+            let r = ((p - a).dot(&d) / d.norm_squared()).clamp(T::zero(), T::one());
+            let q = a + d * r;
+            (T::from_usize(i).unwrap() + r, q, (q - p).norm())
+        })
+        .min_by(|a, b| a.2.partial_cmp(&b.2).unwrap_or(std::cmp::Ordering::Equal))
+        .map(|(s, q, _)| (s, q))
+        .unwrap()
+}
+
+/// Collect the corners of the rectangle passed by walking along the boundary from `from` to `to`
+fn boundary_walk<T: FloatingPoint>(
+    corners: &[Point2<T>; 4],
+    from: T,
+    to: T,
+    ccw: bool,
+) -> Vec<Point2<T>> {
+    let four = T::from_usize(4).unwrap();
+    let (start, end) = if ccw { (from, to) } else { (to, from) };
+    let end = if end < start { end + four } else { end };
+    let mut walk = (1..8)
+        .map(|k| T::from_usize(k).unwrap())
+        .filter(|k| start < *k && *k < end)
+        .map(|k| {
+            let index = k.to_usize().unwrap() % 4;
+            corners[index]
+        })
+        .collect_vec();
+    if !ccw {
+        walk.reverse();
+    }
+    walk
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+    use nalgebra::{Point2, Point3, Vector3};
+
+    use crate::{
+        fillet::{SurfaceBlendType, SurfaceFilletOption},
+        prelude::Contains,
+        surface::NurbsSurface3D,
+    };
+
+    use super::try_blend_section;
+
+    #[test]
+    fn fillet_floor_and_wall() {
+        let floor = NurbsSurface3D::<f64>::plane(Point3::origin(), Vector3::x(), Vector3::y());
+        let wall = NurbsSurface3D::plane(Point3::new(-1., 0., 1.), Vector3::y(), Vector3::z());
+
+        let fillets = floor
+            .try_fillet(&wall, SurfaceFilletOption::new(0.25))
+            .unwrap();
+        assert_eq!(fillets.len(), 1);
+        let fillet = &fillets[0];
+
+        // the floor is trimmed at x = -0.75 & the strip toward the wall is removed
+        let floor = fillet.a();
+        let exterior = floor.exterior().unwrap();
+        assert!(exterior.contains(&Point2::new(0.5, 0.5), None).unwrap());
+        assert!(!exterior.contains(&Point2::new(0.05, 0.5), None).unwrap());
+
+        // the contact curve on the wall lies at z = 0.25
+        let wall = fillet.b();
+        let exterior = wall.exterior().unwrap();
+        let span = &exterior.spans()[0];
+        let (start, end) = span.knots_domain();
+        span.sample_regular_range(start, end, 8)
+            .iter()
+            .for_each(|uv| {
+                let p = wall.surface().point_at(uv.x, uv.y);
+                assert_relative_eq!(p.z, 0.25, epsilon = 1e-3);
+                assert_relative_eq!(p.x, -1., epsilon = 1e-8);
+            });
+    }
+
+    #[test]
+    fn chamfer_floor_and_wall() {
+        let floor = NurbsSurface3D::<f64>::plane(Point3::origin(), Vector3::x(), Vector3::y());
+        let wall = NurbsSurface3D::plane(Point3::new(-1., 0., 1.), Vector3::y(), Vector3::z());
+
+        let option = SurfaceFilletOption::new(0.25).with_blend_type(SurfaceBlendType::Chamfer);
+        let fillets = floor.try_fillet(&wall, option).unwrap();
+        assert_eq!(fillets.len(), 1);
+
+        // the chamfer is the plane x + z = -0.75 between the contact lines
+        let blend = fillets[0].blend();
+        let (u, v) = blend.knots_domain();
+        for i in 0..=4 {
+            let s = u.0 + (u.1 - u.0) * i as f64 / 4.;
+            let t = v.0 + (v.1 - v.0) * 0.5;
+            let p = blend.point_at(s, t);
+            assert_relative_eq!(p.x + p.z, -0.75, epsilon = 1e-3);
+        }
+    }
+
+    #[test]
+    fn blend_section_rejects_opposite_contacts() {
+        let center = Point3::<f64>::origin();
+        let (a, b) = (Point3::new(1., 0., 0.), Point3::new(-1., 0., 0.));
+        assert!(try_blend_section(&center, &a, &b, SurfaceBlendType::Round).is_err());
+
+        // the chamfer is a straight line, so it is fine
+        assert!(try_blend_section(&center, &a, &b, SurfaceBlendType::Chamfer).is_ok());
+
+        let b = Point3::new(0., 1., 0.);
+        let section = try_blend_section(&center, &a, &b, SurfaceBlendType::Round).unwrap();
+        let (start, end) = section.knots_domain();
+        section
+            .sample_regular_range(start, end, 8)
+            .iter()
+            .for_each(|p| assert_relative_eq!(p.coords.norm(), 1., epsilon = 1e-8));
+    }
+}
@@ -1,7 +1,13 @@
 pub mod curve_fillet_option;
 pub mod fillet_compound_curve;
+pub mod fillet_curves;
 pub mod fillet_nurbs_curve;
+pub mod fillet_nurbs_surface;
+pub mod surface_fillet_option;
 pub use curve_fillet_option::*;
+pub use fillet_curves::*;
+pub use fillet_nurbs_surface::*;
+pub use surface_fillet_option::*;
 
 mod helper;
 mod segment;
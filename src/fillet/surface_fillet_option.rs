@@ -0,0 +1,71 @@
+use crate::{misc::FloatingPoint, prelude::SurfaceSurfaceIntersectionOptions};
+
+/// Cross section of the blend surface between two surfaces
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SurfaceBlendType {
+    /// Circular arc of the rolling ball
+    #[default]
+    Round,
+    /// Straight line between the contact points of the rolling ball
+    Chamfer,
+}
+
+/// Option for blending two surfaces by a rolling ball
+#[derive(Debug, Clone)]
+pub struct SurfaceFilletOption<T: FloatingPoint> {
+    /// Radius of the rolling ball
+    radius: T,
+    /// Tolerance for fitting the offset surfaces
+    tolerance: T,
+    /// Cross section of the blend surface
+    blend_type: SurfaceBlendType,
+    /// Options to find the path of the ball center
+    intersection: SurfaceSurfaceIntersectionOptions<T>,
+}
+
+impl<T: FloatingPoint> SurfaceFilletOption<T> {
+    pub fn new(radius: T) -> Self {
+        Self {
+            radius,
+            tolerance: T::from_f64(1e-4).unwrap(),
+            blend_type: Default::default(),
+            intersection: Default::default(),
+        }
+    }
+
+    pub fn radius(&self) -> T {
+        self.radius
+    }
+
+    pub fn tolerance(&self) -> T {
+        self.tolerance
+    }
+
+    pub fn blend_type(&self) -> SurfaceBlendType {
+        self.blend_type
+    }
+
+    pub fn intersection(&self) -> &SurfaceSurfaceIntersectionOptions<T> {
+        &self.intersection
+    }
+
+    pub fn with_radius(mut self, radius: T) -> Self {
+        self.radius = radius;
+        self
+    }
+
+    pub fn with_tolerance(mut self, tolerance: T) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+
+    pub fn with_blend_type(mut self, blend_type: SurfaceBlendType) -> Self {
+        self.blend_type = blend_type;
+        self
+    }
+
+    pub fn with_intersection(mut self, intersection: SurfaceSurfaceIntersectionOptions<T>) -> Self {
+        self.intersection = intersection;
+        self
+    }
+}
@@ -1,7 +1,7 @@
 use crate::misc::FloatingPoint;
 
 /// Hyperparameters for the curve intersection solver.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct CurveIntersectionSolverOptions<T: FloatingPoint> {
     /// Minimum distance between two points to consider them as intersecting.
     pub minimum_distance: T,
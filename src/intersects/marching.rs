@@ -0,0 +1,308 @@
+use itertools::Itertools;
+use nalgebra::{Const, DimMin, Point3, SMatrix, SVector, Vector3};
+
+use crate::{
+    misc::{segment_closest_point, FloatingPoint},
+    prelude::SurfaceSurfaceIntersectionOptions,
+};
+
+/// Maximum number of Newton iterations in the corrector step
+const MAX_CORRECTOR_ITERS: usize = 32;
+
+/// Maximum number of marching steps for a single intersection curve
+const MAX_MARCHING_STEPS: usize = 1 << 14;
+
+/// Constraint to close the system of equations in the corrector step
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum MarchingConstraint<T: FloatingPoint> {
+    /// The traced point lies on the plane defined by the origin & normal
+    Plane(Point3<T>, Vector3<T>),
+    /// The parameter at the index is fixed to the value
+    Parameter(usize, T),
+}
+
+impl<T: FloatingPoint> MarchingConstraint<T> {
+    /// Evaluate the constraint & its gradient at the parameter
+    /// `point`, `du` & `dv` are the traced point & its derivatives by the first two parameters.
+    pub(crate) fn equation<const N: usize>(
+        &self,
+        x: &SVector<T, N>,
+        point: &Point3<T>,
+        du: &Vector3<T>,
+        dv: &Vector3<T>,
+    ) -> (T, SVector<T, N>) {
+        let mut row = SVector::<T, N>::zeros();
+        match self {
+            Self::Plane(origin, normal) => {
+                row[0] = normal.dot(du);
+                row[1] = normal.dot(dv);
+                ((point - origin).dot(normal), row)
+            }
+            Self::Parameter(index, value) => {
+                row[*index] = T::one();
+                (x[*index] - *value, row)
+            }
+        }
+    }
+}
+
+/// Intersection problem traced by the predictor-corrector marching method
+/// The parameter space has N dimensions, and the intersection curve is the solution of N - 1 equations.
+pub(crate) trait MarchingProblem<T: FloatingPoint, const N: usize> {
+    /// Evaluate the traced point in 3D space
+    fn point(&self, x: &SVector<T, N>) -> Point3<T>;
+
+    /// Compute the unit tangent of the intersection curve
+    /// Returns None if the intersected objects are tangent to each other.
+    fn tangent(&self, x: &SVector<T, N>) -> Option<Vector3<T>>;
+
+    /// Project the displacement in 3D space to the parameter space
+    fn predict(&self, x: &SVector<T, N>, delta: &Vector3<T>) -> Option<SVector<T, N>>;
+
+    /// Evaluate the residual & the jacobian of the intersection equations closed by the constraint
+    fn system(
+        &self,
+        x: &SVector<T, N>,
+        constraint: &MarchingConstraint<T>,
+    ) -> (SVector<T, N>, SMatrix<T, N, N>);
+}
+
+/// Predictor-corrector marching method to trace the intersection curves of the problem
+pub(crate) struct Marcher<P, T: FloatingPoint, const N: usize> {
+    problem: P,
+    /// Parameter domains of each dimension
+    domain: [(T, T); N],
+    tolerance: T,
+    step: T,
+    angle: T,
+}
+
+impl<P, T, const N: usize> Marcher<P, T, N>
+where
+    P: MarchingProblem<T, N>,
+    T: FloatingPoint,
+    Const<N>: DimMin<Const<N>, Output = Const<N>>,
+{
+    pub(crate) fn new(
+        problem: P,
+        domain: [(T, T); N],
+        options: &SurfaceSurfaceIntersectionOptions<T>,
+    ) -> Self {
+        Self {
+            problem,
+            domain,
+            tolerance: options.minimum_distance,
+            step: options.marching_step,
+            angle: options.marching_angle,
+        }
+    }
+
+    /// Evaluate the traced point in 3D space
+    pub(crate) fn point(&self, x: &SVector<T, N>) -> Point3<T> {
+        self.problem.point(x)
+    }
+
+    /// Check if the parameter is inside the domain
+    fn contains(&self, x: &SVector<T, N>) -> bool {
+        let eps = T::from_f64(1e-8).unwrap();
+        self.domain
+            .iter()
+            .enumerate()
+            .all(|(i, (lo, hi))| *lo - eps <= x[i] && x[i] <= *hi + eps)
+    }
+
+    /// Polish the approximate solution to be a starting point of the marching
+    pub(crate) fn refine(&self, x: &SVector<T, N>) -> Option<SVector<T, N>> {
+        let t = self.problem.tangent(x)?;
+        let origin = self.problem.point(x);
+        self.correct(x, MarchingConstraint::Plane(origin, t))
+            .filter(|x| self.contains(x))
+    }
+
+    /// Newton's method to find the intersection point under the given constraint
+    fn correct(
+        &self,
+        x: &SVector<T, N>,
+        constraint: MarchingConstraint<T>,
+    ) -> Option<SVector<T, N>> {
+        let mut x = *x;
+        for _ in 0..MAX_CORRECTOR_ITERS {
+            let (residual, jacobian) = self.problem.system(&x, &constraint);
+            if residual.norm() < self.tolerance {
+                return Some(x);
+            }
+
+            let dx = jacobian.lu().solve(&-residual)?;
+            x += dx;
+
+            if x.iter().any(|v| !v.is_finite()) {
+                return None;
+            }
+        }
+        None
+    }
+
+    /// Find the point on the domain boundary between the inner point `x0` and the outer point `x1`
+    fn clip(&self, x0: &SVector<T, N>, x1: &SVector<T, N>) -> Option<SVector<T, N>> {
+        let (s, index, value) = self
+            .domain
+            .iter()
+            .enumerate()
+            .filter_map(|(i, (lo, hi))| {
+                let d = x1[i] - x0[i];
+                if x1[i] < *lo && d.abs() > T::zero() {
+                    Some(((*lo - x0[i]) / d, i, *lo))
+                } else if x1[i] > *hi && d.abs() > T::zero() {
+                    Some(((*hi - x0[i]) / d, i, *hi))
+                } else {
+                    None
+                }
+            })
+            .min_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(std::cmp::Ordering::Equal))?;
+
+        let guess = x0 + (x1 - x0) * s.clamp(T::zero(), T::one());
+        self.correct(&guess, MarchingConstraint::Parameter(index, value))
+            .filter(|x| self.contains(x))
+    }
+
+    /// March along the intersection curve from the starting point
+    /// `sign` determines the direction of the marching
+    /// Returns the traced parameters and whether the curve is closed or not
+    fn march(&self, start: SVector<T, N>, sign: T) -> (Vec<SVector<T, N>>, bool) {
+        let mut points = vec![start];
+        let origin = self.point(&start);
+        let mut prev = match self.problem.tangent(&start) {
+            Some(t) => t * sign,
+            None => return (points, false),
+        };
+
+        let min_step = self.step / T::from_usize(64).unwrap();
+        let mut h = self.step;
+        let mut x = start;
+
+        for _ in 0..MAX_MARCHING_STEPS {
+            let t = match self.problem.tangent(&x) {
+                Some(t) if t.dot(&prev) < T::zero() => -t,
+                Some(t) => t,
+                None => break,
+            };
+
+            let p0 = self.point(&x);
+            let delta = t * h;
+            let predicted = match self.problem.predict(&x, &delta) {
+                Some(dx) => x + dx,
+                None => break,
+            };
+
+            let corrected = self.correct(&predicted, MarchingConstraint::Plane(p0 + delta, t));
+            let candidate = corrected.unwrap_or(predicted);
+
+            if !self.contains(&candidate) {
+                if corrected.is_none() && h > min_step {
+                    h *= T::from_f64(0.5).unwrap();
+                    continue;
+                }
+                // reached to the boundary of the domain
+                if let Some(clipped) = self.clip(&x, &candidate) {
+                    points.push(clipped);
+                }
+                break;
+            }
+
+            let x1 = match corrected {
+                Some(x1) => x1,
+                None if h > min_step => {
+                    h *= T::from_f64(0.5).unwrap();
+                    continue;
+                }
+                None => break,
+            };
+
+            let t1 = match self.problem.tangent(&x1) {
+                Some(t1) if t1.dot(&t) < T::zero() => -t1,
+                Some(t1) => t1,
+                None => {
+                    points.push(x1);
+                    break;
+                }
+            };
+
+            // reduce the step size if the intersection curve turns sharply
+            let angle = t.dot(&t1).clamp(-T::one(), T::one()).acos();
+            if angle > self.angle && h > min_step {
+                h *= T::from_f64(0.5).unwrap();
+                continue;
+            }
+
+            // check if the curve comes back to the starting point
+            let p1 = self.point(&x1);
+            if points.len() > 2 {
+                let (_, closest) = segment_closest_point(&origin, &p0, &p1, T::zero(), T::one());
+                if (origin - closest).norm() < h * T::from_f64(0.5).unwrap() {
+                    points.push(start);
+                    return (points, true);
+                }
+            }
+
+            points.push(x1);
+            x = x1;
+            prev = t1;
+            h = (h * T::from_f64(2.).unwrap()).min(self.step);
+        }
+
+        (points, false)
+    }
+
+    /// Trace the intersection curves from the starting points
+    /// The starting points lying on the traced curves are skipped,
+    /// and the duplicated points closer than the tolerance are removed from the traces.
+    pub(crate) fn trace(&self, seeds: Vec<SVector<T, N>>) -> Vec<Vec<SVector<T, N>>> {
+        let mut traces: Vec<Vec<SVector<T, N>>> = vec![];
+        let threshold = self.step * T::from_f64(0.25).unwrap();
+        for seed in seeds {
+            let p = self.point(&seed);
+            let traced = traces.iter().any(|trace| {
+                trace.iter().tuple_windows().any(|(x0, x1)| {
+                    let p0 = self.point(x0);
+                    let p1 = self.point(x1);
+                    let (_, closest) = segment_closest_point(&p, &p0, &p1, T::zero(), T::one());
+                    (p - closest).norm() < threshold
+                })
+            });
+            if traced {
+                continue;
+            }
+
+            let (forward, closed) = self.march(seed, T::one());
+            let trace = if closed {
+                forward
+            } else {
+                let (backward, _) = self.march(seed, -T::one());
+                backward
+                    .into_iter()
+                    .rev()
+                    .chain(forward.into_iter().skip(1))
+                    .collect()
+            };
+            traces.push(trace);
+        }
+
+        traces
+            .into_iter()
+            .filter_map(|trace| {
+                // Remove duplicated points to avoid the singular interpolation problem
+                let trace = trace
+                    .into_iter()
+                    .coalesce(|x0, x1| {
+                        if (self.point(&x0) - self.point(&x1)).norm() < self.tolerance {
+                            Ok(x0)
+                        } else {
+                            Err((x0, x1))
+                        }
+                    })
+                    .collect_vec();
+                (trace.len() >= 2).then_some(trace)
+            })
+            .collect()
+    }
+}
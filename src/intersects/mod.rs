@@ -2,13 +2,19 @@ pub mod curve_curve;
 pub mod curve_plane;
 pub mod has_intersection;
 pub mod intersection;
+mod marching;
 pub mod surface_curve;
 pub mod surface_plane;
+pub mod surface_ray;
+pub mod surface_surface;
 
 pub use curve_curve::*;
 pub use curve_plane::*;
 pub use has_intersection::*;
 pub use intersection::*;
+pub use surface_plane::*;
+pub use surface_ray::*;
+pub use surface_surface::*;
 
 /// Intersection between two objects trait
 pub trait Intersects<'a, T> {
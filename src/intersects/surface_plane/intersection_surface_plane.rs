@@ -0,0 +1,207 @@
+use argmin::core::ArgminFloat;
+use itertools::Itertools;
+use nalgebra::{Matrix2, Matrix3x2, Point2, Point3, Vector2, Vector3};
+
+use crate::{
+    curve::{NurbsCurve2D, NurbsCurve3D},
+    intersects::marching::{Marcher, MarchingConstraint, MarchingProblem},
+    misc::{FloatingPoint, Plane},
+    prelude::{
+        CurveIntersectionSolverOptions, HasIntersection, Intersects,
+        SurfaceSurfaceIntersectionOptions,
+    },
+    surface::{NurbsSurface3D, UVDirection},
+};
+
+use super::SurfacePlaneIntersection;
+
+impl<'a, T> Intersects<'a, &'a Plane<T>> for NurbsSurface3D<T>
+where
+    T: FloatingPoint + ArgminFloat,
+{
+    type Output = anyhow::Result<Vec<SurfacePlaneIntersection<T>>>;
+    type Option = Option<SurfaceSurfaceIntersectionOptions<T>>;
+
+    /// Find the section curves between a surface and a plane
+    /// Starting points are found by intersecting the iso-curves of the surface with the plane,
+    /// then the section curves are traced by marching along the zero level set of the signed distance to the plane.
+    /// * `plane` - The plane to intersect with
+    /// * `options` - Hyperparameters for the intersection solver
+    /// # Example
+    /// ```
+    /// use curvo::prelude::*;
+    /// use nalgebra::{Point3, Vector3};
+    /// use approx::assert_relative_eq;
+    /// let sphere = NurbsSurface3D::try_sphere(
+    ///     &Point3::origin(),
+    ///     &Vector3::z(),
+    ///     &Vector3::x(),
+    ///     1.
+    /// ).unwrap();
+    ///
+    /// // z = 0.5
+    /// let plane = Plane::new(Vector3::z(), -0.5);
+    /// let sections = sphere.find_intersection(&plane, None).unwrap();
+    /// assert!(!sections.is_empty());
+    ///
+    /// let radius = (1.0_f64 - 0.5 * 0.5).sqrt();
+    /// for section in sections.iter() {
+    ///     let curve = section.curve();
+    ///     let (start, end) = curve.knots_domain();
+    ///     curve.sample_regular_range(start, end, 16).iter().for_each(|p| {
+    ///         assert_relative_eq!(p.z, 0.5, epsilon = 1e-4);
+    ///         assert_relative_eq!(p.coords.xy().norm(), radius, epsilon = 1e-3);
+    ///     });
+    ///
+    ///     // the uv curve is the pre-image of the section curve on the surface
+    ///     let uv = section.uv_curve().point_at(section.uv_curve().knots_domain().1);
+    ///     assert_relative_eq!(sphere.point_at(uv.x, uv.y), curve.point_at(end), epsilon = 1e-4);
+    /// }
+    /// ```
+    fn find_intersection(&'a self, plane: &'a Plane<T>, option: Self::Option) -> Self::Output {
+        let options = option.unwrap_or_default();
+        let (u, v) = self.knots_domain();
+        let marcher = Marcher::new(
+            SurfacePlaneMarching {
+                surface: self,
+                plane,
+            },
+            [u, v],
+            &options,
+        );
+
+        let curve_options = CurveIntersectionSolverOptions::default()
+            .with_step_size_tolerance(options.step_size_tolerance)
+            .with_cost_tolerance(options.cost_tolerance)
+            .with_max_iters(options.max_iters);
+
+        // Find the starting points by intersecting the iso-curves with the plane
+        let division = options.knot_domain_division.max(1);
+        let mut seeds = vec![];
+        for direction in [UVDirection::U, UVDirection::V] {
+            let (start, end) = self.knots_domain_at(direction);
+            let span = (end - start) / T::from_usize(division).unwrap();
+            for i in 0..=division {
+                let t = start + span * T::from_usize(i).unwrap();
+                let iso = self.try_isocurve(t, direction)?;
+                let intersections = iso.find_intersection(plane, Some(curve_options.clone()))?;
+                seeds.extend(intersections.into_iter().map(|it| {
+                    let s = it.a().1;
+                    match direction {
+                        UVDirection::U => Vector2::new(t, s),
+                        UVDirection::V => Vector2::new(s, t),
+                    }
+                }));
+            }
+        }
+        let seeds = seeds
+            .into_iter()
+            .filter_map(|seed| marcher.refine(&seed))
+            .collect_vec();
+
+        // Trace the section curves from the seeds
+        marcher
+            .trace(seeds)
+            .into_iter()
+            .map(|trace| {
+                let degree = options.degree.min(trace.len() - 1);
+                let points = trace.iter().map(|x| marcher.point(x)).collect_vec();
+                let uv = trace.iter().map(|x| Point2::from(*x)).collect_vec();
+                Ok(SurfacePlaneIntersection::new(
+                    NurbsCurve3D::try_interpolate(&points, degree)?,
+                    NurbsCurve2D::try_interpolate(&uv, degree)?,
+                ))
+            })
+            .collect()
+    }
+}
+
+/// Section problem between a surface and a plane
+/// The parameter is the (u, v) of the surface.
+struct SurfacePlaneMarching<'a, T: FloatingPoint> {
+    surface: &'a NurbsSurface3D<T>,
+    plane: &'a Plane<T>,
+}
+
+impl<T: FloatingPoint> MarchingProblem<T, 2> for SurfacePlaneMarching<'_, T> {
+    fn point(&self, x: &Vector2<T>) -> Point3<T> {
+        self.surface.point_at(x.x, x.y)
+    }
+
+    /// Compute the unit tangent of the section curve as the cross product of the surface normal & the plane normal
+    fn tangent(&self, x: &Vector2<T>) -> Option<Vector3<T>> {
+        let n = self.surface.normal_at(x.x, x.y).normalize();
+        let t = n.cross(&self.plane.normal().normalize());
+        let norm = t.norm();
+        if norm.is_finite() && norm > T::from_f64(1e-8).unwrap() {
+            Some(t / norm)
+        } else {
+            // the surface is tangent to the plane
+            None
+        }
+    }
+
+    /// Project the displacement in 3D space to the parameter space of the surface
+    fn predict(&self, x: &Vector2<T>, delta: &Vector3<T>) -> Option<Vector2<T>> {
+        let d = self.surface.rational_derivatives(x.x, x.y, 1);
+        let j = Matrix3x2::from_columns(&[d[1][0], d[0][1]]);
+        let jt = j.transpose();
+        (jt * j).try_inverse().map(|inv| inv * jt * delta)
+    }
+
+    /// The residual is the signed distance to the plane & the constraint
+    fn system(
+        &self,
+        x: &Vector2<T>,
+        constraint: &MarchingConstraint<T>,
+    ) -> (Vector2<T>, Matrix2<T>) {
+        let normal = self.plane.normal();
+        let d = self.surface.rational_derivatives(x.x, x.y, 1);
+        let p = Point3::from(d[0][0]);
+        let distance = self.plane.signed_distance(&p);
+        let (f, row) = constraint.equation(x, &p, &d[1][0], &d[0][1]);
+        let jacobian = Matrix2::new(normal.dot(&d[1][0]), normal.dot(&d[0][1]), row.x, row.y);
+        (Vector2::new(distance, f), jacobian)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+    use nalgebra::{Point3, Vector3};
+
+    use crate::{curve::NurbsCurve3D, misc::Plane, prelude::Intersects, surface::NurbsSurface3D};
+
+    #[test]
+    fn test_cylinder_plane_section() {
+        let circle =
+            NurbsCurve3D::try_circle(&Point3::origin(), &Vector3::x(), &Vector3::y(), 1.).unwrap();
+        let cylinder = NurbsSurface3D::extrude(&circle, &(Vector3::z() * 2.));
+
+        // slanted plane crossing the cylinder
+        let normal = Vector3::new(0., 1., 2.).normalize();
+        let plane = Plane::new(normal, -normal.dot(&Vector3::new(0., 0., 1.)));
+        let sections = cylinder.find_intersection(&plane, None).unwrap();
+        assert_eq!(sections.len(), 1);
+
+        let section = &sections[0];
+        let curve = section.curve();
+        let (start, end) = curve.knots_domain();
+        assert_relative_eq!(curve.point_at(start), curve.point_at(end), epsilon = 1e-6);
+        curve
+            .sample_regular_range(start, end, 32)
+            .iter()
+            .for_each(|p| {
+                assert_relative_eq!(plane.signed_distance(p), 0., epsilon = 1e-4);
+                assert_relative_eq!(p.coords.xy().norm(), 1., epsilon = 1e-4);
+            });
+    }
+
+    #[test]
+    fn test_no_section() {
+        let surface = NurbsSurface3D::<f64>::plane(Point3::origin(), Vector3::x(), Vector3::y());
+        let plane = Plane::new(Vector3::z(), -1.);
+        let sections = surface.find_intersection(&plane, None).unwrap();
+        assert!(sections.is_empty());
+    }
+}
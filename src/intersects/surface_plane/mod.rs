@@ -1 +1,4 @@
+pub mod intersection_surface_plane;
+pub mod surface_plane_intersection;
 
+pub use surface_plane_intersection::*;
@@ -0,0 +1,34 @@
+use crate::{
+    curve::{NurbsCurve2D, NurbsCurve3D},
+    misc::FloatingPoint,
+};
+
+/// A struct representing a section curve between a surface & a plane.
+/// The curve is represented in 3D space and as a pre-image in the uv space of the surface.
+#[derive(Debug, Clone)]
+pub struct SurfacePlaneIntersection<T: FloatingPoint> {
+    /// The section curve in 3D space.
+    curve: NurbsCurve3D<T>,
+    /// The section curve in the uv space of the surface.
+    uv: NurbsCurve2D<T>,
+}
+
+impl<T: FloatingPoint> SurfacePlaneIntersection<T> {
+    pub fn new(curve: NurbsCurve3D<T>, uv: NurbsCurve2D<T>) -> Self {
+        Self { curve, uv }
+    }
+
+    /// The section curve in 3D space.
+    pub fn curve(&self) -> &NurbsCurve3D<T> {
+        &self.curve
+    }
+
+    /// The section curve in the uv space of the surface.
+    pub fn uv_curve(&self) -> &NurbsCurve2D<T> {
+        &self.uv
+    }
+
+    pub fn as_tuple(self) -> (NurbsCurve3D<T>, NurbsCurve2D<T>) {
+        (self.curve, self.uv)
+    }
+}
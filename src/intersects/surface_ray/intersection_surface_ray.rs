@@ -0,0 +1,252 @@
+use std::cmp::Ordering;
+
+use argmin::core::ArgminFloat;
+use itertools::Itertools;
+use nalgebra::{Const, Matrix3, Point3, Vector3};
+use num_traits::Float;
+
+use crate::{
+    misc::{FloatingPoint, Ray},
+    prelude::{
+        BoundingBox, BoundingBoxTree, CurveIntersectionSolverOptions, HasIntersection,
+        Intersection, Intersects, SurfaceBoundingBoxTree,
+    },
+    surface::{NurbsSurface3D, UVDirection},
+};
+
+/// A struct representing the intersection of surface & ray.
+/// `a` holds the hit point & (u, v) parameter on the surface, `b` holds the hit point & parameter along the ray.
+pub type SurfaceRayIntersection<T> = Intersection<Point3<T>, (T, T), T>;
+
+/// Maximum number of Newton iterations to polish the hit point
+const MAX_POLISH_ITERS: usize = 16;
+
+impl<'a, T> Intersects<'a, &'a Ray<T, Const<3>>> for NurbsSurface3D<T>
+where
+    T: FloatingPoint + ArgminFloat,
+{
+    type Output = anyhow::Result<Vec<SurfaceRayIntersection<T>>>;
+    type Option = Option<CurveIntersectionSolverOptions<T>>;
+
+    /// Find the intersections between the surface and the ray.
+    /// The bounding box tree of the surface is traversed to find the patches hit by the ray,
+    /// then the hit point in each patch is solved by Newton's method.
+    /// Only hits in the positive direction of the ray (parameter >= 0) are returned, sorted by the ray parameter.
+    /// # Example
+    /// ```
+    /// use curvo::prelude::*;
+    /// use nalgebra::{Point3, Vector3};
+    /// use approx::assert_relative_eq;
+    /// let unit_sphere = NurbsSurface3D::try_sphere(
+    ///     &Point3::origin(),
+    ///     &Vector3::z(),
+    ///     &Vector3::x(),
+    ///     1.
+    /// ).unwrap();
+    /// let ray = Ray::new(Point3::new(-3.0, 0.0, 0.0), Vector3::x());
+    /// let hits = unit_sphere.find_intersection(&ray, None).unwrap();
+    /// assert_eq!(hits.len(), 2);
+    ///
+    /// // the nearest hit comes first
+    /// let (p0, t0) = hits[0].b();
+    /// assert_relative_eq!(*t0, 2., epsilon = 1e-6);
+    /// assert_relative_eq!(p0.x, -1., epsilon = 1e-6);
+    ///
+    /// // the hit point can be evaluated by the (u, v) parameter
+    /// let (p1, (u, v)) = hits[1].a();
+    /// assert_relative_eq!(unit_sphere.point_at(*u, *v), *p1, epsilon = 1e-6);
+    /// assert_relative_eq!(p1.x, 1., epsilon = 1e-6);
+    ///
+    /// // the ray pointing away from the sphere does not hit
+    /// let ray = Ray::new(Point3::new(-3.0, 0.0, 0.0), -Vector3::x());
+    /// assert!(unit_sphere.find_intersection(&ray, None).unwrap().is_empty());
+    /// ```
+    fn find_intersection(
+        &'a self,
+        ray: &'a Ray<T, Const<3>>,
+        option: Self::Option,
+    ) -> Self::Output {
+        let options = option.unwrap_or_default();
+        anyhow::ensure!(
+            ray.direction().norm_squared() > T::zero(),
+            "The direction of the ray must be non-zero"
+        );
+
+        // Collect the leaves of the bounding box tree hit by the ray
+        let div = T::one() / T::from_usize(options.knot_domain_division).unwrap();
+        let interval = self.knots_domain_interval();
+        let tree = SurfaceBoundingBoxTree::new(
+            self,
+            UVDirection::U,
+            Some((interval.0 * div, interval.1 * div)),
+        );
+        let mut trees = vec![tree];
+        let mut seeds = vec![];
+        while let Some(tree) = trees.pop() {
+            let (t0, t1) = match clip_ray(ray, &tree.bounding_box()) {
+                Some(range) => range,
+                None => continue,
+            };
+            if tree.is_dividable() {
+                let (l, r) = tree.try_divide()?;
+                trees.push(l);
+                trees.push(r);
+            } else {
+                let half = T::from_f64(0.5).unwrap();
+                let (u, v) = tree.surface().knots_domain();
+                seeds.push(Vector3::new(
+                    (u.0 + u.1) * half,
+                    (v.0 + v.1) * half,
+                    (t0 + t1) * half,
+                ));
+            }
+        }
+
+        let (u_domain, v_domain) = self.knots_domain();
+        let eps = T::from_f64(1e-8).unwrap();
+        let hits = seeds
+            .into_iter()
+            .filter_map(|seed| polish(self, ray, seed, options.minimum_distance))
+            .filter(|x| {
+                x.z >= -eps
+                    && u_domain.0 - eps <= x.x
+                    && x.x <= u_domain.1 + eps
+                    && v_domain.0 - eps <= x.y
+                    && x.y <= v_domain.1 + eps
+            })
+            .map(|x| {
+                let t = Float::max(x.z, T::zero());
+                SurfaceRayIntersection::new(
+                    (self.point_at(x.x, x.y), (x.x, x.y)),
+                    (ray.point_at(t), t),
+                )
+            })
+            .sorted_by(|a, b| a.b().1.partial_cmp(&b.b().1).unwrap_or(Ordering::Equal))
+            .collect_vec();
+
+        // remove the duplicated hits found in the neighboring bounding boxes
+        let hits = hits
+            .into_iter()
+            .coalesce(|x, y| {
+                if (x.b().0 - y.b().0).norm() < options.minimum_distance {
+                    Ok(x)
+                } else {
+                    Err((x, y))
+                }
+            })
+            .collect();
+
+        Ok(hits)
+    }
+}
+
+/// Clip the ray by the bounding box with the slab method
+/// Returns the range of the ray parameter inside the (slightly enlarged) bounding box
+fn clip_ray<T: FloatingPoint>(
+    ray: &Ray<T, Const<3>>,
+    bb: &BoundingBox<T, Const<3>>,
+) -> Option<(T, T)> {
+    let margin = bb.size().norm() * T::from_f64(1e-2).unwrap() + T::from_f64(1e-6).unwrap();
+    let origin = ray.origin();
+    let direction = ray.direction();
+
+    let mut t0 = T::zero();
+    let mut t1 = T::max_value().unwrap();
+    for i in 0..3 {
+        let lo = bb.min()[i] - margin;
+        let hi = bb.max()[i] + margin;
+        if direction[i].abs() < T::default_epsilon() {
+            if origin[i] < lo || hi < origin[i] {
+                return None;
+            }
+        } else {
+            let inv = T::one() / direction[i];
+            let (near, far) = {
+                let a = (lo - origin[i]) * inv;
+                let b = (hi - origin[i]) * inv;
+                if a < b {
+                    (a, b)
+                } else {
+                    (b, a)
+                }
+            };
+            t0 = t0.max(near);
+            t1 = t1.min(far);
+            if t1 < t0 {
+                return None;
+            }
+        }
+    }
+    Some((t0, t1))
+}
+
+/// Polish the hit parameter (u, v, t) by Newton's method to solve S(u, v) = o + t * d
+fn polish<T: FloatingPoint>(
+    surface: &NurbsSurface3D<T>,
+    ray: &Ray<T, Const<3>>,
+    x: Vector3<T>,
+    tolerance: T,
+) -> Option<Vector3<T>> {
+    let direction = ray.direction();
+    let mut x = x;
+    for _ in 0..MAX_POLISH_ITERS {
+        let d = surface.rational_derivatives(x.x, x.y, 1);
+        let residual = Point3::from(d[0][0]) - ray.point_at(x.z);
+        if residual.norm() < tolerance {
+            return Some(x);
+        }
+        let jacobian = Matrix3::from_columns(&[d[1][0], d[0][1], -direction]);
+        let dx = jacobian.lu().solve(&-residual)?;
+        x += dx;
+        if x.iter().any(|v| !v.is_finite()) {
+            return None;
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+    use nalgebra::{Point3, Vector3};
+
+    use crate::{
+        misc::Ray,
+        prelude::{HasIntersection, Intersects},
+        surface::NurbsSurface3D,
+    };
+
+    #[test]
+    fn test_plane_ray_intersection() {
+        let plane = NurbsSurface3D::<f64>::plane(Point3::origin(), Vector3::x(), Vector3::y());
+
+        // oblique ray
+        let ray = Ray::new(Point3::new(0.2, -0.3, 2.), Vector3::new(0.1, 0.2, -1.));
+        let hits = plane.find_intersection(&ray, None).unwrap();
+        assert_eq!(hits.len(), 1);
+        let (p, t) = hits[0].b();
+        assert_relative_eq!(*t, 2., epsilon = 1e-8);
+        assert_relative_eq!(*p, Point3::new(0.4, 0.1, 0.), epsilon = 1e-8);
+
+        // ray passing outside of the plane
+        let ray = Ray::new(Point3::new(2., 0., 1.), -Vector3::z());
+        assert!(plane.find_intersection(&ray, None).unwrap().is_empty());
+
+        // ray parallel to the plane
+        let ray = Ray::new(Point3::new(-2., 0., 1.), Vector3::x());
+        assert!(plane.find_intersection(&ray, None).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_ray_origin_inside_sphere() {
+        let sphere =
+            NurbsSurface3D::try_sphere(&Point3::origin(), &Vector3::z(), &Vector3::x(), 1.)
+                .unwrap();
+        let ray = Ray::new(Point3::origin(), Vector3::new(1., 1., 1.));
+        let hits = sphere.find_intersection(&ray, None).unwrap();
+        assert_eq!(hits.len(), 1);
+        let (p, _) = hits[0].a();
+        assert_relative_eq!(p.coords.norm(), 1., epsilon = 1e-6);
+        assert!(p.x > 0. && p.y > 0. && p.z > 0.);
+    }
+}
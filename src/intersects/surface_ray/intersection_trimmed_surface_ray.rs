@@ -0,0 +1,72 @@
+use argmin::core::ArgminFloat;
+use nalgebra::{Const, Point2};
+
+use crate::{
+    misc::{FloatingPoint, Ray},
+    prelude::{Contains, CurveIntersectionSolverOptions, HasIntersection, Intersects},
+    surface::TrimmedSurface,
+};
+
+use super::SurfaceRayIntersection;
+
+impl<'a, T> Intersects<'a, &'a Ray<T, Const<3>>> for TrimmedSurface<T>
+where
+    T: FloatingPoint + ArgminFloat,
+{
+    type Output = anyhow::Result<Vec<SurfaceRayIntersection<T>>>;
+    type Option = Option<CurveIntersectionSolverOptions<T>>;
+
+    /// Find the intersections between the trimmed surface and the ray.
+    /// Hits on the base surface are rejected if the (u, v) parameter is outside the exterior or inside the interiors.
+    /// # Example
+    /// ```
+    /// use curvo::prelude::*;
+    /// use nalgebra::{Point2, Point3, Vector2, Vector3};
+    /// use approx::assert_relative_eq;
+    /// let plane = NurbsSurface3D::plane(Point3::origin(), Vector3::x(), Vector3::y());
+    ///
+    /// // punch a hole of radius 0.25 at the center of the plane
+    /// let hole = NurbsCurve2D::try_circle(&Point2::new(0.5, 0.5), &Vector2::x(), &Vector2::y(), 0.25).unwrap();
+    /// let trimmed = TrimmedSurface::new(plane, None, vec![hole.into()]);
+    ///
+    /// let ray = Ray::new(Point3::new(0., 0., 1.), -Vector3::z());
+    /// assert!(trimmed.find_intersection(&ray, None).unwrap().is_empty());
+    ///
+    /// let ray = Ray::new(Point3::new(0.75, 0., 1.), -Vector3::z());
+    /// let hits = trimmed.find_intersection(&ray, None).unwrap();
+    /// assert_eq!(hits.len(), 1);
+    /// assert_relative_eq!(hits[0].a().0, Point3::new(0.75, 0., 0.), epsilon = 1e-8);
+    /// ```
+    fn find_intersection(
+        &'a self,
+        ray: &'a Ray<T, Const<3>>,
+        option: Self::Option,
+    ) -> Self::Output {
+        let hits = self.surface().find_intersection(ray, option.clone())?;
+
+        let mut inside = vec![];
+        for hit in hits.into_iter() {
+            let (u, v) = hit.a().1;
+            let uv = Point2::new(u, v);
+            let in_exterior = match self.exterior() {
+                Some(exterior) => exterior.contains(&uv, option.clone())?,
+                None => true,
+            };
+            if !in_exterior {
+                continue;
+            }
+            let mut in_interiors = false;
+            for interior in self.interiors() {
+                if interior.contains(&uv, option.clone())? {
+                    in_interiors = true;
+                    break;
+                }
+            }
+            if !in_interiors {
+                inside.push(hit);
+            }
+        }
+
+        Ok(inside)
+    }
+}
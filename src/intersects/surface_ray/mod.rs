@@ -0,0 +1,4 @@
+pub mod intersection_surface_ray;
+pub mod intersection_trimmed_surface_ray;
+
+pub use intersection_surface_ray::*;
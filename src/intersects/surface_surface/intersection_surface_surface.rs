@@ -0,0 +1,268 @@
+use argmin::core::{ArgminFloat, Executor, State};
+use itertools::Itertools;
+use nalgebra::{Matrix3x2, Matrix4, Point2, Point3, Vector2, Vector3, Vector4};
+
+use crate::{
+    curve::{NurbsCurve2D, NurbsCurve3D},
+    intersects::{
+        marching::{Marcher, MarchingConstraint, MarchingProblem},
+        surface_curve::SurfaceCurveIntersectionBFGS,
+    },
+    misc::FloatingPoint,
+    prelude::{BoundingBoxTraversal, Intersects, SurfaceBoundingBoxTree},
+    surface::{NurbsSurface3D, UVDirection},
+};
+
+use super::{
+    SurfaceSurfaceIntersection, SurfaceSurfaceIntersectionOptions,
+    SurfaceSurfaceIntersectionProblem, SurfaceSurfaceParam,
+};
+
+impl<'a, T> Intersects<'a, &'a NurbsSurface3D<T>> for NurbsSurface3D<T>
+where
+    T: FloatingPoint + ArgminFloat,
+{
+    type Output = anyhow::Result<Vec<SurfaceSurfaceIntersection<T>>>;
+    type Option = Option<SurfaceSurfaceIntersectionOptions<T>>;
+
+    /// Find the intersection curves between two surfaces.
+    /// Starting points are searched by the bounding box traversal & quasi-Newton method,
+    /// then the intersection curves are traced by marching along the tangent of the intersection.
+    /// The traced points are fitted by NURBS curves in 3D space and in the uv space of both surfaces.
+    /// CAUTION: This method is experimental and may not work as expected.
+    /// # Example
+    /// ```
+    /// use curvo::prelude::*;
+    /// use nalgebra::{Point3, Vector3};
+    /// use approx::assert_relative_eq;
+    /// let sphere = NurbsSurface3D::try_sphere(
+    ///     &Point3::origin(),
+    ///     &Vector3::z(),
+    ///     &Vector3::x(),
+    ///     1.
+    /// ).unwrap();
+    /// let plane = NurbsSurface3D::plane(Point3::origin(), Vector3::x() * 2., Vector3::y() * 2.);
+    /// let intersections = sphere.find_intersection(&plane, None).unwrap();
+    /// assert!(!intersections.is_empty());
+    ///
+    /// for it in intersections.iter() {
+    ///     let curve = it.curve();
+    ///     let (start, end) = curve.knots_domain();
+    ///     curve.sample_regular_range(start, end, 16).iter().for_each(|p| {
+    ///         assert_relative_eq!(p.coords.norm(), 1., epsilon = 1e-3);
+    ///         assert_relative_eq!(p.z, 0., epsilon = 1e-3);
+    ///     });
+    ///
+    ///     // the uv curves are the pre-images of the intersection curve on each surface
+    ///     let uv = it.a_curve().point_at(it.a_curve().knots_domain().0);
+    ///     let p = sphere.point_at(uv.x, uv.y);
+    ///     assert_relative_eq!(p, curve.point_at(start), epsilon = 1e-4);
+    /// }
+    /// ```
+    fn find_intersection(
+        &'a self,
+        other: &'a NurbsSurface3D<T>,
+        option: Self::Option,
+    ) -> Self::Output {
+        let options = option.unwrap_or_default();
+        let (au, av) = self.knots_domain();
+        let (bu, bv) = other.knots_domain();
+        let marcher = Marcher::new(
+            SurfaceSurfaceMarching { a: self, b: other },
+            [au, av, bu, bv],
+            &options,
+        );
+
+        let div = T::one() / T::from_usize(options.knot_domain_division).unwrap();
+        let ia = self.knots_domain_interval();
+        let ib = other.knots_domain_interval();
+        let ta = SurfaceBoundingBoxTree::new(self, UVDirection::U, Some((ia.0 * div, ia.1 * div)));
+        let tb = SurfaceBoundingBoxTree::new(other, UVDirection::U, Some((ib.0 * div, ib.1 * div)));
+
+        let traversed = BoundingBoxTraversal::try_traverse(ta, tb)?;
+
+        let seeds = traversed
+            .into_pairs_iter()
+            .filter_map(|(a, b)| {
+                let sa = a.surface_owned();
+                let sb = b.surface_owned();
+
+                let half = T::from_f64(0.5).unwrap();
+                let (au, av) = sa.knots_domain();
+                let (bu, bv) = sb.knots_domain();
+
+                let problem = SurfaceSurfaceIntersectionProblem::new(&sa, &sb);
+
+                // Define initial parameter vector at the center of the patches
+                let init_param = Vector4::new(
+                    (au.0 + au.1) * half,
+                    (av.0 + av.1) * half,
+                    (bu.0 + bu.1) * half,
+                    (bv.0 + bv.1) * half,
+                );
+
+                // Set up solver
+                let solver = SurfaceCurveIntersectionBFGS::<T>::new()
+                    .with_step_size_tolerance(options.step_size_tolerance)
+                    .with_cost_tolerance(options.cost_tolerance);
+
+                // Run solver
+                let res = Executor::new(problem, solver)
+                    .configure(|state| {
+                        state
+                            .param(init_param)
+                            .inv_hessian(Matrix4::identity())
+                            .max_iters(options.max_iters)
+                    })
+                    .run();
+
+                res.ok()
+                    .and_then(|r| r.state().get_best_param().cloned())
+                    .and_then(|param| marcher.refine(&param))
+            })
+            .collect_vec();
+
+        // Trace the intersection curves from the seeds
+        marcher
+            .trace(seeds)
+            .into_iter()
+            .map(|trace| {
+                let degree = options.degree.min(trace.len() - 1);
+                let points = trace.iter().map(|x| marcher.point(x)).collect_vec();
+                let a = trace.iter().map(|x| Point2::new(x.x, x.y)).collect_vec();
+                let b = trace.iter().map(|x| Point2::new(x.z, x.w)).collect_vec();
+                Ok(SurfaceSurfaceIntersection::new(
+                    NurbsCurve3D::try_interpolate(&points, degree)?,
+                    NurbsCurve2D::try_interpolate(&a, degree)?,
+                    NurbsCurve2D::try_interpolate(&b, degree)?,
+                ))
+            })
+            .collect()
+    }
+}
+
+/// Intersection problem between two surfaces
+/// The parameter is in (u_a, v_a, u_b, v_b) order, and the traced point is evaluated on the first surface.
+struct SurfaceSurfaceMarching<'a, T: FloatingPoint> {
+    a: &'a NurbsSurface3D<T>,
+    b: &'a NurbsSurface3D<T>,
+}
+
+impl<T: FloatingPoint> MarchingProblem<T, 4> for SurfaceSurfaceMarching<'_, T> {
+    fn point(&self, x: &SurfaceSurfaceParam<T>) -> Point3<T> {
+        self.a.point_at(x.x, x.y)
+    }
+
+    /// Compute the unit tangent of the intersection curve as the cross product of both surface normals
+    fn tangent(&self, x: &SurfaceSurfaceParam<T>) -> Option<Vector3<T>> {
+        let na = self.a.normal_at(x.x, x.y).normalize();
+        let nb = self.b.normal_at(x.z, x.w).normalize();
+        let t = na.cross(&nb);
+        let norm = t.norm();
+        if norm.is_finite() && norm > T::from_f64(1e-8).unwrap() {
+            Some(t / norm)
+        } else {
+            // surfaces are tangent to each other
+            None
+        }
+    }
+
+    /// Project the displacement in 3D space to the parameter spaces of both surfaces
+    fn predict(&self, x: &SurfaceSurfaceParam<T>, delta: &Vector3<T>) -> Option<Vector4<T>> {
+        let da = self.a.rational_derivatives(x.x, x.y, 1);
+        let db = self.b.rational_derivatives(x.z, x.w, 1);
+        let solve = |su: &Vector3<T>, sv: &Vector3<T>| -> Option<Vector2<T>> {
+            let j = Matrix3x2::from_columns(&[*su, *sv]);
+            let jt = j.transpose();
+            (jt * j).try_inverse().map(|inv| inv * jt * delta)
+        };
+        let pa = solve(&da[1][0], &da[0][1])?;
+        let pb = solve(&db[1][0], &db[0][1])?;
+        Some(Vector4::new(pa.x, pa.y, pb.x, pb.y))
+    }
+
+    /// The residual is the difference between the points on both surfaces & the constraint
+    fn system(
+        &self,
+        x: &SurfaceSurfaceParam<T>,
+        constraint: &MarchingConstraint<T>,
+    ) -> (Vector4<T>, Matrix4<T>) {
+        let da = self.a.rational_derivatives(x.x, x.y, 1);
+        let db = self.b.rational_derivatives(x.z, x.w, 1);
+        let r = da[0][0] - db[0][0];
+        let (sua, sva, sub, svb) = (&da[1][0], &da[0][1], &db[1][0], &db[0][1]);
+        let (f, row) = constraint.equation(x, &Point3::from(da[0][0]), sua, sva);
+        let jacobian = Matrix4::new(
+            sua.x, sva.x, -sub.x, -svb.x, //
+            sua.y, sva.y, -sub.y, -svb.y, //
+            sua.z, sva.z, -sub.z, -svb.z, //
+            row.x, row.y, row.z, row.w,
+        );
+        (Vector4::new(r.x, r.y, r.z, f), jacobian)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+    use nalgebra::{Point3, Vector3};
+
+    use crate::{prelude::Intersects, surface::NurbsSurface3D};
+
+    #[test]
+    fn test_plane_plane_intersection() {
+        let a = NurbsSurface3D::<f64>::plane(Point3::origin(), Vector3::x(), Vector3::y());
+        let b = NurbsSurface3D::<f64>::plane(Point3::origin(), Vector3::x(), Vector3::z());
+        let intersections = a.find_intersection(&b, None).unwrap();
+        assert_eq!(intersections.len(), 1);
+
+        let curve = intersections[0].curve();
+        let (start, end) = curve.knots_domain();
+        let (p0, p1) = (curve.point_at(start), curve.point_at(end));
+        assert_relative_eq!((p0 - p1).norm(), 2., epsilon = 1e-6);
+        curve
+            .sample_regular_range(start, end, 8)
+            .iter()
+            .for_each(|p| {
+                assert_relative_eq!(p.y, 0., epsilon = 1e-6);
+                assert_relative_eq!(p.z, 0., epsilon = 1e-6);
+            });
+    }
+
+    #[test]
+    fn test_cylinder_plane_intersection() {
+        let circle = crate::curve::NurbsCurve3D::try_circle(
+            &Point3::new(0., 0., -1.),
+            &Vector3::x(),
+            &Vector3::y(),
+            1.,
+        )
+        .unwrap();
+        let cylinder = NurbsSurface3D::extrude(&circle, &(Vector3::z() * 2.));
+        let plane = NurbsSurface3D::plane(Point3::origin(), Vector3::x() * 2., Vector3::y() * 2.);
+        let intersections = cylinder.find_intersection(&plane, None).unwrap();
+        assert_eq!(intersections.len(), 1);
+
+        let it = &intersections[0];
+        let curve = it.curve();
+        let (start, end) = curve.knots_domain();
+        assert_relative_eq!(curve.point_at(start), curve.point_at(end), epsilon = 1e-6);
+        curve
+            .sample_regular_range(start, end, 32)
+            .iter()
+            .for_each(|p| {
+                assert_relative_eq!(p.coords.xy().norm(), 1., epsilon = 1e-4);
+                assert_relative_eq!(p.z, 0., epsilon = 1e-6);
+            });
+
+        // pre-image on the plane is a circle of radius 0.5 in the uv space
+        let uv = it.b_curve();
+        let (start, end) = uv.knots_domain();
+        uv.sample_regular_range(start, end, 32)
+            .iter()
+            .for_each(|p| {
+                let q = plane.point_at(p.x, p.y);
+                assert_relative_eq!(q.coords.xy().norm(), 1., epsilon = 1e-4);
+            });
+    }
+}
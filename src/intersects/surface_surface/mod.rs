@@ -0,0 +1,13 @@
+pub mod intersection_surface_surface;
+pub mod surface_surface_intersection;
+pub mod surface_surface_intersection_bfgs;
+pub mod surface_surface_intersection_options;
+pub mod surface_surface_intersection_problem;
+
+use nalgebra::Vector4;
+pub use surface_surface_intersection::*;
+pub use surface_surface_intersection_options::*;
+pub use surface_surface_intersection_problem::*;
+
+pub type SurfaceSurfaceParam<T> = Vector4<T>;
+pub type SurfaceSurfaceGradient<T> = Vector4<T>;
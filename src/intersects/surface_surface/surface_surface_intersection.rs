@@ -0,0 +1,41 @@
+use crate::{
+    curve::{NurbsCurve2D, NurbsCurve3D},
+    misc::FloatingPoint,
+};
+
+/// A struct representing an intersection curve between two surfaces.
+/// The curve is represented in 3D space and as pre-images in the uv space of both surfaces.
+#[derive(Debug, Clone)]
+pub struct SurfaceSurfaceIntersection<T: FloatingPoint> {
+    /// The intersection curve in 3D space.
+    curve: NurbsCurve3D<T>,
+    /// The intersection curve in the uv space of the first surface.
+    a: NurbsCurve2D<T>,
+    /// The intersection curve in the uv space of the second surface.
+    b: NurbsCurve2D<T>,
+}
+
+impl<T: FloatingPoint> SurfaceSurfaceIntersection<T> {
+    pub fn new(curve: NurbsCurve3D<T>, a: NurbsCurve2D<T>, b: NurbsCurve2D<T>) -> Self {
+        Self { curve, a, b }
+    }
+
+    /// The intersection curve in 3D space.
+    pub fn curve(&self) -> &NurbsCurve3D<T> {
+        &self.curve
+    }
+
+    /// The intersection curve in the uv space of the first surface.
+    pub fn a_curve(&self) -> &NurbsCurve2D<T> {
+        &self.a
+    }
+
+    /// The intersection curve in the uv space of the second surface.
+    pub fn b_curve(&self) -> &NurbsCurve2D<T> {
+        &self.b
+    }
+
+    pub fn as_tuple(self) -> (NurbsCurve3D<T>, NurbsCurve2D<T>, NurbsCurve2D<T>) {
+        (self.curve, self.a, self.b)
+    }
+}
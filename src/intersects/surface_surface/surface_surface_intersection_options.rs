@@ -0,0 +1,82 @@
+use crate::misc::FloatingPoint;
+
+/// Hyperparameters for the surface & surface intersection solver.
+/// The same hyperparameters are used for the surface & plane intersection.
+#[derive(Clone, Debug)]
+pub struct SurfaceSurfaceIntersectionOptions<T: FloatingPoint> {
+    /// Minimum distance between two points to consider them as intersecting.
+    pub minimum_distance: T,
+    /// Knot domain division for the threshold of the bounding box tree.
+    /// Starting points of the intersection curves are searched in the pairs of divided surface patches.
+    pub knot_domain_division: usize,
+    /// Tolerance for the step size in the line search.
+    pub step_size_tolerance: T,
+    /// Tolerance for the cost function to determine convergence.
+    pub cost_tolerance: T,
+    /// Maximum number of iterations for the Newton method.
+    pub max_iters: u64,
+    /// Maximum step length in 3D space while marching along the intersection curve.
+    pub marching_step: T,
+    /// Maximum angle in radians between tangents of consecutive marching points.
+    /// The marching step is reduced until the turning angle falls below this value.
+    pub marching_angle: T,
+    /// Degree of the fitted intersection curves.
+    pub degree: usize,
+}
+
+impl<T: FloatingPoint> Default for SurfaceSurfaceIntersectionOptions<T> {
+    fn default() -> Self {
+        Self {
+            minimum_distance: T::from_f64(1e-6).unwrap(),
+            knot_domain_division: 16,
+            step_size_tolerance: T::from_f64(1e-8).unwrap(),
+            cost_tolerance: T::from_f64(1e-12).unwrap(),
+            max_iters: 200,
+            marching_step: T::from_f64(5e-2).unwrap(),
+            marching_angle: T::from_f64(1e-1).unwrap(),
+            degree: 3,
+        }
+    }
+}
+
+impl<T: FloatingPoint> SurfaceSurfaceIntersectionOptions<T> {
+    pub fn with_minimum_distance(mut self, minimum_distance: T) -> Self {
+        self.minimum_distance = minimum_distance;
+        self
+    }
+
+    pub fn with_knot_domain_division(mut self, knot_domain_division: usize) -> Self {
+        self.knot_domain_division = knot_domain_division;
+        self
+    }
+
+    pub fn with_step_size_tolerance(mut self, step_size_tolerance: T) -> Self {
+        self.step_size_tolerance = step_size_tolerance;
+        self
+    }
+
+    pub fn with_cost_tolerance(mut self, cost_tolerance: T) -> Self {
+        self.cost_tolerance = cost_tolerance;
+        self
+    }
+
+    pub fn with_max_iters(mut self, max_iters: u64) -> Self {
+        self.max_iters = max_iters;
+        self
+    }
+
+    pub fn with_marching_step(mut self, marching_step: T) -> Self {
+        self.marching_step = marching_step;
+        self
+    }
+
+    pub fn with_marching_angle(mut self, marching_angle: T) -> Self {
+        self.marching_angle = marching_angle;
+        self
+    }
+
+    pub fn with_degree(mut self, degree: usize) -> Self {
+        self.degree = degree;
+        self
+    }
+}
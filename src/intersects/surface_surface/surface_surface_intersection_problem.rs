@@ -0,0 +1,83 @@
+use argmin::core::{CostFunction, Gradient};
+
+use nalgebra::{
+    allocator::Allocator, Const, DefaultAllocator, DimName, DimNameDiff, DimNameSub, Vector4, U1,
+};
+
+use crate::{misc::FloatingPoint, surface::NurbsSurface};
+
+use super::{SurfaceSurfaceGradient, SurfaceSurfaceParam};
+
+// Gradient & CostFunction provider for finding the intersection between two surfaces.
+// The parameter vector is laid out as (u_a, v_a, u_b, v_b).
+pub struct SurfaceSurfaceIntersectionProblem<'a, T: FloatingPoint, D: DimName>
+where
+    DefaultAllocator: Allocator<D>,
+{
+    /// The first surface to find the intersection with.
+    a: &'a NurbsSurface<T, D>,
+    /// The second surface to find the intersection with.
+    b: &'a NurbsSurface<T, D>,
+}
+
+impl<'a, T: FloatingPoint, D: DimName> SurfaceSurfaceIntersectionProblem<'a, T, D>
+where
+    DefaultAllocator: Allocator<D>,
+{
+    pub fn new(a: &'a NurbsSurface<T, D>, b: &'a NurbsSurface<T, D>) -> Self {
+        SurfaceSurfaceIntersectionProblem { a, b }
+    }
+}
+
+impl<T: FloatingPoint, D: DimName> Gradient for SurfaceSurfaceIntersectionProblem<'_, T, D>
+where
+    DefaultAllocator: Allocator<D>,
+    D: DimNameSub<U1>,
+    DefaultAllocator: Allocator<DimNameDiff<D, U1>>,
+{
+    type Param = SurfaceSurfaceParam<T>;
+    type Gradient = SurfaceSurfaceGradient<T>;
+
+    fn gradient(&self, param: &Self::Param) -> Result<Self::Gradient, anyhow::Error> {
+        let da = self.a.rational_derivatives(param.x, param.y, 1);
+        let db = self.b.rational_derivatives(param.z, param.w, 1);
+        let r = &da[0][0] - &db[0][0];
+        Ok(Vector4::new(
+            da[1][0].dot(&r),
+            da[0][1].dot(&r),
+            -db[1][0].dot(&r),
+            -db[0][1].dot(&r),
+        ) * T::from_f64(2.).unwrap())
+    }
+}
+
+impl<T: FloatingPoint, D: DimName> CostFunction for SurfaceSurfaceIntersectionProblem<'_, T, D>
+where
+    DefaultAllocator: Allocator<D>,
+    D: DimNameSub<U1>,
+    DefaultAllocator: Allocator<DimNameDiff<D, U1>>,
+{
+    type Param = SurfaceSurfaceParam<T>;
+    type Output = T;
+
+    fn cost(&self, param: &Self::Param) -> Result<Self::Output, anyhow::Error> {
+        let p1 = self.a.point(param.x, param.y);
+        let p2 = self.b.point(param.z, param.w);
+        let c1 = p1.coords;
+        let c2 = p2.coords;
+        let idx = D::dim() - 1;
+        let w1 = c1[idx];
+        let w2 = c2[idx];
+
+        if w1 != T::zero() && w2 != T::zero() {
+            let v1 =
+                c1.generic_view((0, 0), (<D as DimNameSub<U1>>::Output::name(), Const::<1>)) / w1;
+            let v2 =
+                c2.generic_view((0, 0), (<D as DimNameSub<U1>>::Output::name(), Const::<1>)) / w2;
+            let d = v1 - v2;
+            Ok(d.norm_squared())
+        } else {
+            Err(anyhow::anyhow!("Parameter out of domain"))
+        }
+    }
+}
@@ -87,7 +87,7 @@ pub mod prelude {
     pub use crate::knot::*;
     pub use crate::misc::{
         binomial::*, curvature::*, end_points::*, floating_point::*, frenet_frame::*,
-        invertible::*, line::*, orientation::*, plane::*, polygon_boundary::*, ray::*,
+        invertible::*, law::*, line::*, orientation::*, plane::*, polygon_boundary::*, ray::*,
         transformable::*, transpose::*, trigonometry::*,
     };
     pub use crate::offset::*;
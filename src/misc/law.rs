@@ -0,0 +1,106 @@
+use std::{fmt, sync::Arc};
+
+use super::FloatingPoint;
+
+/// Law of a value varying along a parameter
+/// Used for the values changing along curves, such as offset distances or sweep scales & twists.
+#[derive(Clone)]
+pub enum Law<T> {
+    /// Constant value over the whole range
+    Constant(T),
+    /// Value given as a function of the parameter
+    Function(Arc<dyn Fn(T) -> T + Send + Sync>),
+    /// Value linearly interpolated between the (parameter, value) keys sorted by the parameter.
+    /// The value is clamped to the first or last key outside the range of the keys.
+    Keys(Vec<(T, T)>),
+}
+
+impl<T: FloatingPoint> Law<T> {
+    /// Create a law given by the function of the parameter
+    pub fn function<F>(f: F) -> Self
+    where
+        F: Fn(T) -> T + Send + Sync + 'static,
+    {
+        Self::Function(Arc::new(f))
+    }
+
+    /// Create a law interpolated between the (parameter, value) keys
+    /// # Example
+    /// ```
+    /// use curvo::prelude::*;
+    /// use approx::assert_relative_eq;
+    /// let law = Law::keys(vec![(1., 2.), (0., 1.)]);
+    /// assert_relative_eq!(law.at(-1.), 1.);
+    /// assert_relative_eq!(law.at(0.25), 1.25);
+    /// assert_relative_eq!(law.at(2.), 2.);
+    /// ```
+    pub fn keys(keys: Vec<(T, T)>) -> Self {
+        let mut keys = keys;
+        keys.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(std::cmp::Ordering::Equal));
+        Self::Keys(keys)
+    }
+
+    /// Evaluate the law at the parameter
+    pub fn at(&self, t: T) -> T {
+        match self {
+            Self::Constant(v) => *v,
+            Self::Function(f) => f(t),
+            Self::Keys(keys) => {
+                let (first, last) = match (keys.first(), keys.last()) {
+                    (Some(first), Some(last)) => (first, last),
+                    _ => return T::zero(),
+                };
+                if t <= first.0 {
+                    return first.1;
+                }
+                if t >= last.0 {
+                    return last.1;
+                }
+                keys.windows(2)
+                    .find(|w| w[0].0 <= t && t <= w[1].0)
+                    .map(|w| {
+                        let span = w[1].0 - w[0].0;
+                        if span <= T::zero() {
+                            w[1].1
+                        } else {
+                            let r = (t - w[0].0) / span;
+                            w[0].1 * (T::one() - r) + w[1].1 * r
+                        }
+                    })
+                    .unwrap_or(last.1)
+            }
+        }
+    }
+
+    /// Check if the value is constant over the whole range
+    pub fn is_constant(&self) -> bool {
+        matches!(self, Self::Constant(_))
+    }
+}
+
+impl<T: FloatingPoint> From<T> for Law<T> {
+    fn from(value: T) -> Self {
+        Self::Constant(value)
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Law<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Constant(v) => f.debug_tuple("Constant").field(v).finish(),
+            Self::Function(_) => f.debug_tuple("Function").finish(),
+            Self::Keys(keys) => f.debug_tuple("Keys").field(keys).finish(),
+        }
+    }
+}
+
+impl<T: PartialEq> PartialEq for Law<T> {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Constant(a), Self::Constant(b)) => a == b,
+            (Self::Function(a), Self::Function(b)) => Arc::ptr_eq(a, b),
+            (Self::Keys(a), Self::Keys(b)) => a == b,
+            _ => false,
+        }
+    }
+}
@@ -0,0 +1,173 @@
+use itertools::Itertools;
+use nalgebra::{allocator::Allocator, DMatrix, DefaultAllocator, DimName, OPoint};
+
+use crate::knot::KnotVector;
+
+use super::FloatingPoint;
+
+/// Build the knot vector by averaging the parameters & the matrix of basis functions evaluated at the parameters
+pub(crate) fn interpolation_matrix<T: FloatingPoint>(
+    parameters: &[T],
+    degree: usize,
+) -> (KnotVector<T>, DMatrix<T>) {
+    let n = parameters.len();
+    let (first, last) = (parameters[0], parameters[n - 1]);
+    let inner = (1..(n - degree)).map(|i| {
+        parameters[i..(i + degree)]
+            .iter()
+            .fold(T::zero(), |acc, u| acc + *u)
+            / T::from_usize(degree).unwrap()
+    });
+    let knots = KnotVector::new(
+        std::iter::repeat_n(first, degree + 1)
+            .chain(inner)
+            .chain(std::iter::repeat_n(last, degree + 1))
+            .collect(),
+    );
+
+    let m = basis_matrix(&knots, degree, n, parameters);
+    (knots, m)
+}
+
+/// Build the matrix of the basis functions of the knot vector with the number of control points evaluated at the parameters
+pub(crate) fn basis_matrix<T: FloatingPoint>(
+    knots: &KnotVector<T>,
+    degree: usize,
+    count: usize,
+    parameters: &[T],
+) -> DMatrix<T> {
+    let mut a = DMatrix::zeros(parameters.len(), count);
+    parameters.iter().enumerate().for_each(|(i, u)| {
+        let span = knots.find_knot_span_index(count - 1, degree, *u);
+        let basis = knots.basis_functions(span, *u, degree);
+        basis.into_iter().enumerate().for_each(|(j, b)| {
+            a[(i, span - degree + j)] = b;
+        });
+    });
+    a
+}
+
+/// Solve the least squares problems sharing the matrix by the normal equations
+pub(crate) fn try_solve_least_squares<T: FloatingPoint>(
+    m: DMatrix<T>,
+    rhs: Vec<DMatrix<T>>,
+) -> anyhow::Result<Vec<DMatrix<T>>> {
+    let mt = m.transpose();
+    let lu = (&mt * &m).lu();
+    rhs.iter()
+        .map(|rhs| {
+            lu.solve(&(&mt * rhs))
+                .ok_or(anyhow::anyhow!("Failed to solve the approximation"))
+        })
+        .collect()
+}
+
+/// Solve the symmetric positive definite banded system by the Cholesky decomposition
+/// `band[(i, d)]` holds the entry of the lower triangle at the row `i` & the column `i - d`,
+/// so the number of columns of `band` is the half bandwidth + 1.
+/// The decomposition takes O(n w^2) time & O(n w) memory for the size n & the half bandwidth w.
+pub(crate) fn try_solve_banded_cholesky<T: FloatingPoint>(
+    band: DMatrix<T>,
+    rhs: DMatrix<T>,
+) -> anyhow::Result<DMatrix<T>> {
+    let n = band.nrows();
+    let w = band.ncols() - 1;
+
+    // decompose into L L^T in place, where l[(i, d)] holds L at (i, i - d)
+    let mut l = band;
+    for i in 0..n {
+        for j in i.saturating_sub(w)..=i {
+            let sum = (i.saturating_sub(w).max(j.saturating_sub(w))..j)
+                .fold(l[(i, i - j)], |acc, k| acc - l[(i, i - k)] * l[(j, j - k)]);
+            if i == j {
+                anyhow::ensure!(sum > T::zero(), "The matrix is not positive definite");
+                l[(i, 0)] = sum.sqrt();
+            } else {
+                l[(i, i - j)] = sum / l[(j, 0)];
+            }
+        }
+    }
+
+    // forward substitution by L, then backward substitution by L^T
+    let mut x = rhs;
+    for c in 0..x.ncols() {
+        for i in 0..n {
+            let sum =
+                (i.saturating_sub(w)..i).fold(x[(i, c)], |acc, k| acc - l[(i, i - k)] * x[(k, c)]);
+            x[(i, c)] = sum / l[(i, 0)];
+        }
+        for i in (0..n).rev() {
+            let sum = ((i + 1)..n.min(i + w + 1))
+                .fold(x[(i, c)], |acc, k| acc - l[(k, k - i)] * x[(k, c)]);
+            x[(i, c)] = sum / l[(i, 0)];
+        }
+    }
+    Ok(x)
+}
+
+/// Interpolate each column of the homogeneous points at the parameters
+/// Returns the knot vector shared by the columns & the control points of each column
+#[allow(clippy::type_complexity)]
+pub(crate) fn try_interpolate_columns<T: FloatingPoint, D: DimName>(
+    columns: &[Vec<OPoint<T, D>>],
+    parameters: &[T],
+    degree: usize,
+) -> anyhow::Result<(KnotVector<T>, Vec<Vec<OPoint<T, D>>>)>
+where
+    DefaultAllocator: Allocator<D>,
+{
+    let (knots, m) = interpolation_matrix(parameters, degree);
+    let solved = try_solve_columns(
+        m,
+        columns
+            .iter()
+            .map(|column| DMatrix::from_fn(column.len(), D::dim(), |i, k| column[i][k]))
+            .collect(),
+    )?;
+    let columns = solved
+        .iter()
+        .map(|solved| {
+            (0..solved.nrows())
+                .map(|i| OPoint::from_slice(solved.row(i).transpose().as_slice()))
+                .collect_vec()
+        })
+        .collect_vec();
+    Ok((knots, columns))
+}
+
+/// Solve the linear systems sharing the matrix
+fn try_solve_columns<T: FloatingPoint>(
+    m: DMatrix<T>,
+    rhs: Vec<DMatrix<T>>,
+) -> anyhow::Result<Vec<DMatrix<T>>> {
+    let lu = m.lu();
+    rhs.iter()
+        .map(|rhs| {
+            lu.solve(rhs)
+                .ok_or(anyhow::anyhow!("Failed to solve the interpolation"))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+    use nalgebra::DMatrix;
+
+    #[test]
+    fn test_banded_cholesky() {
+        // symmetric positive definite matrix with the half bandwidth 2
+        let n = 6;
+        let dense = DMatrix::<f64>::from_fn(n, n, |i, j| match i.abs_diff(j) {
+            0 => 6. + i as f64,
+            1 => -1.5,
+            2 => 0.5,
+            _ => 0.,
+        });
+        let band = DMatrix::from_fn(n, 3, |i, d| if d <= i { dense[(i, i - d)] } else { 0. });
+        let rhs = DMatrix::from_fn(n, 2, |i, k| (i * 3 + k) as f64 - 4.);
+
+        let solved = super::try_solve_banded_cholesky(band, rhs.clone()).unwrap();
+        assert_relative_eq!(dense * solved, rhs, epsilon = 1e-10);
+    }
+}
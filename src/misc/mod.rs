@@ -4,8 +4,10 @@ pub mod end_points;
 pub mod floating_point;
 pub mod frenet_frame;
 pub mod invertible;
+pub mod law;
 pub mod line;
 pub mod line_string_helper;
+pub mod linear_algebra;
 pub mod orientation;
 pub mod plane;
 pub mod polygon_boundary;
@@ -18,6 +20,7 @@ pub use curvature::*;
 pub use end_points::*;
 pub use floating_point::*;
 pub use invertible::*;
+pub use law::*;
 pub use line::*;
 pub use line_string_helper::*;
 pub use orientation::*;
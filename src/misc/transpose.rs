@@ -1,3 +1,4 @@
+use itertools::Itertools;
 use nalgebra::{allocator::Allocator, DefaultAllocator, DimName, OPoint};
 
 use super::FloatingPoint;
@@ -17,3 +18,10 @@ where
     });
     transposed
 }
+
+/// Transpose the grid of points
+pub(crate) fn transpose<P: Clone>(grid: &[Vec<P>]) -> Vec<Vec<P>> {
+    (0..grid[0].len())
+        .map(|j| grid.iter().map(|row| row[j].clone()).collect_vec())
+        .collect_vec()
+}
@@ -1,34 +1,60 @@
-use crate::{misc::FloatingPoint, offset::CurveOffsetCornerType};
+use nalgebra::Vector3;
+
+use crate::{
+    misc::{FloatingPoint, Law},
+    offset::CurveOffsetCornerType,
+    prelude::CurveIntersectionSolverOptions,
+    surface::NurbsSurface3D,
+};
+
+/// Offset distance along the curve, evaluated at the curve parameter
+pub type CurveOffsetDistance<T> = Law<T>;
 
 /// Offset option for NURBS curves
 #[derive(Debug, Clone, PartialEq)]
-pub struct CurveOffsetOption<T> {
+pub struct CurveOffsetOption<T: FloatingPoint> {
     /// Offset distance
-    distance: T,
+    distance: CurveOffsetDistance<T>,
     /// Normal tolerance for tessellation
     normal_tolerance: T,
     /// Knot tolerance for reducing knots
     knot_tolerance: T,
     /// Corner type
     corner_type: CurveOffsetCornerType,
+    /// Intersection solver options to clean up the offset boundaries of regions
+    intersection_option: Option<CurveIntersectionSolverOptions<T>>,
 }
 
 impl<T: FloatingPoint> Default for CurveOffsetOption<T> {
     fn default() -> Self {
         Self {
-            distance: T::zero(),
+            distance: CurveOffsetDistance::Constant(T::zero()),
             normal_tolerance: T::from_f64(1e-4).unwrap(),
             knot_tolerance: T::from_f64(1e-4).unwrap(),
             corner_type: Default::default(),
+            intersection_option: None,
         }
     }
 }
 
-impl<T> CurveOffsetOption<T> {
-    pub fn distance(&self) -> &T {
+impl<T: FloatingPoint> CurveOffsetOption<T> {
+    /// Get the offset distance
+    /// For a distance varying along the curve, the distance at the curve parameter zero is returned,
+    /// so use `variable_distance` or `distance_at` instead.
+    pub fn distance(&self) -> T {
+        self.distance.at(T::zero())
+    }
+
+    /// Get the offset distance which may vary along the curve
+    pub fn variable_distance(&self) -> &CurveOffsetDistance<T> {
         &self.distance
     }
 
+    /// Get the offset distance at the given curve parameter
+    pub fn distance_at(&self, t: T) -> T {
+        self.distance.at(t)
+    }
+
     pub fn normal_tolerance(&self) -> &T {
         &self.normal_tolerance
     }
@@ -41,7 +67,17 @@ impl<T> CurveOffsetOption<T> {
         self.corner_type
     }
 
+    pub fn intersection_option(&self) -> &Option<CurveIntersectionSolverOptions<T>> {
+        &self.intersection_option
+    }
+
     pub fn with_distance(mut self, distance: T) -> Self {
+        self.distance = CurveOffsetDistance::Constant(distance);
+        self
+    }
+
+    /// Set the distance varying along the curve
+    pub fn with_variable_distance(mut self, distance: CurveOffsetDistance<T>) -> Self {
         self.distance = distance;
         self
     }
@@ -60,4 +96,45 @@ impl<T> CurveOffsetOption<T> {
         self.corner_type = ty;
         self
     }
+
+    pub fn with_intersection_option(
+        mut self,
+        option: Option<CurveIntersectionSolverOptions<T>>,
+    ) -> Self {
+        self.intersection_option = option;
+        self
+    }
+}
+
+/// Reference to determine the offset direction of 3D NURBS curves
+#[derive(Debug, Clone)]
+pub enum CurveOffsetReference<'a, T: FloatingPoint> {
+    /// Offset on the plane perpendicular to the normal.
+    /// A positive distance offsets the curve toward `tangent x normal`.
+    Normal(Vector3<T>),
+    /// Offset along the normal of the surface on which the curve lies
+    Surface(&'a NurbsSurface3D<T>),
+}
+
+/// Offset option for 3D NURBS curves
+#[derive(Debug, Clone)]
+pub struct CurveOffsetOption3D<'a, T: FloatingPoint> {
+    /// Reference to determine the offset direction
+    reference: CurveOffsetReference<'a, T>,
+    /// Base option for offsetting
+    option: CurveOffsetOption<T>,
+}
+
+impl<'a, T: FloatingPoint> CurveOffsetOption3D<'a, T> {
+    pub fn new(reference: CurveOffsetReference<'a, T>, option: CurveOffsetOption<T>) -> Self {
+        Self { reference, option }
+    }
+
+    pub fn reference(&self) -> &CurveOffsetReference<'a, T> {
+        &self.reference
+    }
+
+    pub fn option(&self) -> &CurveOffsetOption<T> {
+        &self.option
+    }
 }
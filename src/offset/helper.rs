@@ -29,44 +29,51 @@ pub fn sharp_corner_intersection<T: FloatingPoint>(
     let l1 = to_line_helper(&(v2 - d1), v3);
 
     let it = geo::algorithm::line_intersection::line_intersection(l0, l1);
-    let it = it
-        .and_then(|it| match it {
-            LineIntersection::SinglePoint {
-                intersection: p,
-                is_proper: _,
-            } => Some(p),
-            _ => None,
-        })
-        .ok_or(anyhow::anyhow!("no intersection"))?;
+    let it = it.and_then(|it| match it {
+        LineIntersection::SinglePoint {
+            intersection: p,
+            is_proper: _,
+        } => Some(p),
+        _ => None,
+    });
 
-    Ok(Point2::new(
-        T::from_f64(it.x).unwrap(),
-        T::from_f64(it.y).unwrap(),
-    ))
+    match it {
+        Some(it) => Ok(Point2::new(
+            T::from_f64(it.x).unwrap(),
+            T::from_f64(it.y).unwrap(),
+        )),
+        None => {
+            // the segments are too short (or inverted) to reach the corner, so intersect the infinite lines
+            let t0 = v1 - v0;
+            let t1 = v3 - v2;
+            let det = t0.x * t1.y - t0.y * t1.x;
+            anyhow::ensure!(det.abs() > T::default_epsilon(), "no intersection");
+            let d = v2 - v0;
+            let s = (d.x * t1.y - d.y * t1.x) / det;
+            Ok(v0 + t0 * s)
+        }
+    }
 }
 
-/// Create a round corner between two points (v1 & v2)
-/// v0---v1
-///       \
-///        \
+/// Create a round corner between two points (v1 & v2) around the corner of the original curve
+/// ----v1
+///     | \
+/// center \
 ///         v2
 pub fn round_corner<T: FloatingPoint>(
-    v0: &Point2<T>,
+    center: &Point2<T>,
     v1: &Point2<T>,
     v2: &Point2<T>,
     distance: T,
 ) -> anyhow::Result<NurbsCurve2D<T>> {
-    let t = (v1 - v0).normalize();
     let sign = distance.signum();
-    let n = Vector2::new(t.y, -t.x);
-    let d = n * distance;
-    let center = v1 - d;
-
     let d0 = v1 - center;
     let d1 = v2 - center;
+    let n = d0.normalize() * sign;
+    let t = Vector2::new(-n.y, n.x);
     let angle = d0.angle(&d1);
     let angle = angle.abs();
-    NurbsCurve2D::try_arc(&center, &(n * sign), &t, distance.abs(), T::zero(), angle)
+    NurbsCurve2D::try_arc(center, &(n * sign), &t, distance.abs(), T::zero(), angle)
 }
 
 /// Create a smooth corner between two points (v1 & v2)
@@ -2,8 +2,12 @@ pub mod curve_offset_option;
 mod helper;
 pub mod offset_compound_curve;
 pub mod offset_nurbs_curve;
+pub mod offset_nurbs_curve_3d;
+pub mod offset_nurbs_surface;
+pub mod offset_region;
 mod vertex;
 pub use curve_offset_option::*;
+pub use offset_nurbs_surface::*;
 
 /// Corner type for offsetting NURBS curves
 #[derive(Debug, Clone, Copy, PartialEq)]
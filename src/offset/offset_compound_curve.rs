@@ -35,18 +35,20 @@ where
 
         let corners = offset
             .windows(2)
-            .map(|window| {
+            .zip(self.spans().iter())
+            .map(|(window, span)| {
                 let w0 = &window[0];
                 let w1 = &window[1];
-                find_corner(w0, w1, &option)
+                find_corner(w0, w1, span, &option)
             })
             .collect::<anyhow::Result<Vec<_>>>()?;
 
         let last_corner = if is_closed {
             let last = offset.last();
             let head = offset.first();
-            match (last, head) {
-                (Some(last), Some(head)) => find_corner(last, head, &option)?,
+            let span = self.spans().last();
+            match (last, head, span) {
+                (Some(last), Some(head), Some(span)) => find_corner(last, head, span, &option)?,
                 _ => None,
             }
         } else {
@@ -139,17 +141,26 @@ fn trim_polyline<T: FloatingPoint>(
 }
 
 /// Find the corner between two compound curves
+/// `span` is the original span ending at the corner
 fn find_corner<T: FloatingPoint>(
     s0: &[CompoundCurve2D<T>],
     s1: &[CompoundCurve2D<T>],
+    span: &NurbsCurve2D<T>,
     option: &CurveOffsetOption<T>,
 ) -> anyhow::Result<Option<Corner<T>>> {
     match (s0.len(), s1.len()) {
         (1, 1) => {
             let last = s0[0].spans().last();
             let head = s1[0].spans().first();
+            let (_, end) = span.knots_domain();
             match (last, head) {
-                (Some(last), Some(head)) => corner(last, head, option),
+                (Some(last), Some(head)) => corner(
+                    last,
+                    head,
+                    &span.point_at(end),
+                    option.distance_at(end),
+                    option,
+                ),
                 _ => Ok(None),
             }
         }
@@ -157,10 +168,12 @@ fn find_corner<T: FloatingPoint>(
     }
 }
 
-/// Find the corner between two spans
+/// Find the corner between two spans offset by the distance from the original corner point
 fn corner<T: FloatingPoint>(
     c0: &NurbsCurve2D<T>,
     c1: &NurbsCurve2D<T>,
+    origin: &Point2<T>,
+    distance: T,
     option: &CurveOffsetOption<T>,
 ) -> anyhow::Result<Option<Corner<T>>> {
     let last_degree = c0.degree();
@@ -191,17 +204,17 @@ fn corner<T: FloatingPoint>(
                 match option.corner_type() {
                     CurveOffsetCornerType::None => unreachable!(),
                     CurveOffsetCornerType::Sharp => {
-                        let delta = option.distance().abs() * T::from_f64(2.0).unwrap();
+                        let delta = distance.abs() * T::from_f64(2.0).unwrap();
                         let it = sharp_corner_intersection([s0[0], s0[1], s1[0], s1[1]], delta)?;
                         let corner = NurbsCurve2D::polyline(&[*s0[1], it, *s1[0]], false);
                         Ok(Some(Corner::Curve(corner)))
                     }
                     CurveOffsetCornerType::Round => {
-                        let arc = round_corner(s0[0], s0[1], s1[0], *option.distance())?;
+                        let arc = round_corner(origin, s0[1], s1[0], distance)?;
                         Ok(Some(Corner::Curve(arc)))
                     }
                     CurveOffsetCornerType::Smooth => {
-                        let bezier = smooth_corner(s0[0], s0[1], s1[0], s1[1], *option.distance())?;
+                        let bezier = smooth_corner(s0[0], s0[1], s1[0], s1[1], distance)?;
                         Ok(Some(Corner::Curve(bezier)))
                     }
                     CurveOffsetCornerType::Chamfer => {
@@ -26,29 +26,52 @@ where
     type Option = CurveOffsetOption<T>;
 
     /// Offset the NURBS curve by a given option
+    /// The distance is evaluated at the curve parameter, so that the variable distance can be applied.
+    /// # Example
+    /// ```
+    /// use curvo::prelude::*;
+    /// use nalgebra::Point2;
+    /// use approx::assert_relative_eq;
+    ///
+    /// // tapered offset of the polyline from 0.1 to 0.3
+    /// let polyline = NurbsCurve2D::polyline(&[
+    ///     Point2::new(0., 0.),
+    ///     Point2::new(1., 0.),
+    ///     Point2::new(1., 1.),
+    /// ], true);
+    /// let option = CurveOffsetOption::default()
+    ///     .with_variable_distance(CurveOffsetDistance::keys(vec![(0., 0.1), (1., 0.3)]))
+    ///     .with_corner_type(CurveOffsetCornerType::Sharp);
+    /// let offset = polyline.offset(option).unwrap();
+    /// let pts = offset[0].spans()[0].dehomogenized_control_points();
+    /// assert_relative_eq!(pts[0], Point2::new(0., -0.1), epsilon = 1e-8);
+    /// assert_relative_eq!(pts[pts.len() - 1], Point2::new(1.3, 1.), epsilon = 1e-8);
+    /// ```
     fn offset(&'a self, option: Self::Option) -> Self::Output {
-        let distance = *option.distance();
         let corner_type = option.corner_type();
 
         let is_closed = self.is_closed();
 
-        let offset = |point: &Point2<T>, t: &Vector2<T>| {
+        let offset = |point: &Point2<T>, t: &Vector2<T>, param: T| {
             let normal = Vector2::new(t.y, -t.x);
-            let d = normal * distance;
+            let d = normal * option.distance_at(param);
             point + d
         };
 
         if self.degree() == 1 {
             let pts = self.dehomogenized_control_points();
+            // the parameter at each control point of the polyline
+            let params = (0..pts.len()).map(|i| self.knots()[i + 1]).collect_vec();
             let p_segments = pts
                 .windows(2)
-                .map(|w| {
+                .enumerate()
+                .map(|(i, w)| {
                     let p0 = &w[0];
                     let p1 = &w[1];
                     let tangent = p1 - p0;
                     let t = tangent.normalize();
-                    let start = offset(p0, &t);
-                    let end = offset(p1, &t);
+                    let start = offset(p0, &t, params[i]);
+                    let end = offset(p1, &t, params[i + 1]);
                     PointSegment::new(start, end)
                 })
                 .collect_vec();
@@ -116,8 +139,6 @@ where
                     unreachable!()
                 }
                 CurveOffsetCornerType::Sharp => {
-                    let delta = distance.abs() * T::from_f64(2.0).unwrap();
-
                     let n = segments.len();
 
                     let pts = segments
@@ -132,6 +153,8 @@ where
                                 };
                                 match prev {
                                     Some(prev) => {
+                                        let delta = option.distance_at(params[i]).abs()
+                                            * T::from_f64(2.0).unwrap();
                                         let v0 = prev.start.clone();
                                         let v1 = prev.end.clone();
                                         let v2 = s.start.clone();
@@ -180,11 +203,12 @@ where
                     }
                     let next = &segments[(cursor + 1) % segments.len()];
 
-                    let v0 = &cur.start;
                     let v1 = &cur.end;
                     let v2 = &next.start;
 
-                    let arc = round_corner(v0.inner(), v1.inner(), v2.inner(), distance)?;
+                    let distance = option.distance_at(params[cursor + 1]);
+                    let center = &pts[(cursor + 1) % pts.len()];
+                    let arc = round_corner(center, v1.inner(), v2.inner(), distance)?;
                     Ok(Some(arc))
                 })?,
                 CurveOffsetCornerType::Smooth => try_connect(&segments, |cursor| {
@@ -198,6 +222,7 @@ where
                     let v1 = &cur.end;
                     let v2 = &next.start;
                     let v3 = &next.end;
+                    let distance = option.distance_at(params[cursor + 1]);
                     let bezier =
                         smooth_corner(v0.inner(), v1.inner(), v2.inner(), v3.inner(), distance)?;
 
@@ -258,9 +283,9 @@ where
             let knot_tol = *option.knot_tolerance();
             let tess = tessellate_nurbs_curve(self, norm_tol)
                 .into_iter()
-                .map(|(p, t)| {
+                .map(|(param, p, t)| {
                     let t = t.normalize();
-                    offset(&p, &t)
+                    offset(&p, &t, param)
                 })
                 .collect_vec();
             let mut res = Self::try_interpolate(&tess, self.degree())?;
@@ -350,12 +375,13 @@ where
     Ok(spans.into_iter().flatten().collect_vec())
 }
 
-/// tessellate the NURBS curve & return the points and tangent vectors
+/// tessellate the NURBS curve & return the parameters, points and tangent vectors
 #[allow(clippy::type_complexity)]
-fn tessellate_nurbs_curve<T, D>(
+pub(super) fn tessellate_nurbs_curve<T, D>(
     curve: &NurbsCurve<T, D>,
     normal_tolerance: T,
 ) -> Vec<(
+    T,
     OPoint<T, DimNameDiff<D, U1>>,
     OVector<T, DimNameDiff<D, U1>>,
 )>
@@ -369,7 +395,7 @@ where
     let mut rng = rand::rng();
     let (start, end) = curve.knots_domain();
     tessellate_curve_adaptive(curve, start, end, normal_tolerance, &mut rng, &|t, p| {
-        (p, curve.tangent_at(t))
+        (t, p, curve.tangent_at(t))
     })
 }
 
@@ -379,7 +405,7 @@ mod tests {
 
     use crate::{
         curve::NurbsCurve2D,
-        offset::{CurveOffsetCornerType, CurveOffsetOption, Offset},
+        offset::{CurveOffsetCornerType, CurveOffsetDistance, CurveOffsetOption, Offset},
     };
 
     #[test]
@@ -468,4 +494,39 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn offset_variable_distance() {
+        let points = vec![
+            Point2::new(0.0, 0.0),
+            Point2::new(1.0, 0.0),
+            Point2::new(1.0, 1.0),
+            Point2::new(0.0, 1.0),
+        ];
+        let polyline = NurbsCurve2D::polyline(&points, false);
+        let option = CurveOffsetOption::default()
+            .with_variable_distance(CurveOffsetDistance::function(|t: f64| 0.1 + t * 0.1))
+            .with_corner_type(CurveOffsetCornerType::Round);
+        let res = polyline.offset(option).unwrap();
+        assert_eq!(res.len(), 1);
+
+        // the round corners are centered at the original corners with the local distance as radius
+        let spans = res[0].spans();
+        let arcs = spans.iter().filter(|s| s.degree() != 1).collect::<Vec<_>>();
+        assert_eq!(arcs.len(), 2);
+        [(points[1], 0.2), (points[2], 0.3)]
+            .iter()
+            .zip(arcs.iter())
+            .for_each(|((corner, radius), arc)| {
+                let (start, end) = arc.knots_domain();
+                arc.sample_regular_range(start, end, 8)
+                    .iter()
+                    .for_each(|p| {
+                        assert!(((p - corner).norm() - radius).abs() < 1e-8);
+                    });
+            });
+
+        let pts = spans.last().unwrap().dehomogenized_control_points();
+        assert_eq!(pts.last(), Some(&Point2::new(0.0, 1.4)));
+    }
 }
@@ -0,0 +1,178 @@
+use argmin::core::ArgminFloat;
+use itertools::Itertools;
+use nalgebra::{Matrix3, Matrix4, Vector3};
+
+use crate::{
+    curve::NurbsCurve3D,
+    dimension::try_flatten_curve,
+    misc::{FloatingPoint, Transformable},
+    offset::{
+        curve_offset_option::{CurveOffsetOption, CurveOffsetOption3D, CurveOffsetReference},
+        offset_nurbs_curve::tessellate_nurbs_curve,
+        Offset,
+    },
+    prelude::ElevateDimension,
+    region::CompoundCurve3D,
+    surface::NurbsSurface3D,
+};
+
+impl<'a, T> Offset<'a, T> for NurbsCurve3D<T>
+where
+    T: FloatingPoint + ArgminFloat,
+{
+    type Output = anyhow::Result<Vec<CompoundCurve3D<T>>>;
+    type Option = CurveOffsetOption3D<'a, T>;
+
+    /// Offset the 3D NURBS curve by a given option
+    /// With the normal reference, the planar curve is offset in its plane honoring the corner type.
+    /// With the surface reference, the curve lying on the surface is offset along the surface normal.
+    /// # Example
+    /// ```
+    /// use curvo::prelude::*;
+    /// use nalgebra::{Point3, Vector3};
+    /// use approx::assert_relative_eq;
+    ///
+    /// // square on the plane x = 1 facing +x direction
+    /// let square = NurbsCurve3D::polyline(&[
+    ///     Point3::new(1., 0., 0.),
+    ///     Point3::new(1., 1., 0.),
+    ///     Point3::new(1., 1., 1.),
+    ///     Point3::new(1., 0., 1.),
+    ///     Point3::new(1., 0., 0.),
+    /// ], false);
+    /// let option = CurveOffsetOption3D::new(
+    ///     CurveOffsetReference::Normal(Vector3::x()),
+    ///     CurveOffsetOption::default()
+    ///         .with_distance(0.25)
+    ///         .with_corner_type(CurveOffsetCornerType::Sharp),
+    /// );
+    /// let offset = square.offset(option).unwrap();
+    /// assert_eq!(offset.len(), 1);
+    /// let pts = offset[0].spans()[0].dehomogenized_control_points();
+    /// assert_relative_eq!(pts[0], Point3::new(1., -0.25, -0.25), epsilon = 1e-8);
+    /// assert_relative_eq!(pts[2], Point3::new(1., 1.25, 1.25), epsilon = 1e-8);
+    /// ```
+    fn offset(&'a self, option: Self::Option) -> Self::Output {
+        match option.reference() {
+            CurveOffsetReference::Normal(normal) => {
+                try_offset_on_plane(self, normal, option.option().clone())
+            }
+            CurveOffsetReference::Surface(surface) => {
+                try_offset_on_surface(self, surface, option.option())
+            }
+        }
+    }
+}
+
+/// Offset the planar curve in the plane by flattening it into the local 2D coordinates
+fn try_offset_on_plane<T: FloatingPoint>(
+    curve: &NurbsCurve3D<T>,
+    normal: &Vector3<T>,
+    option: CurveOffsetOption<T>,
+) -> anyhow::Result<Vec<CompoundCurve3D<T>>> {
+    let norm = normal.norm();
+    anyhow::ensure!(norm > T::default_epsilon(), "The normal must be non-zero");
+    let normal = normal / norm;
+
+    // choose the x axis perpendicular to the normal
+    let axis = normal
+        .iter()
+        .map(|v| v.abs())
+        .position_min_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal))
+        .unwrap_or(0);
+    let x_axis = Vector3::ith(axis, T::one()).cross(&normal).normalize();
+    let y_axis = normal.cross(&x_axis);
+
+    let origin = curve
+        .dehomogenized_control_points()
+        .first()
+        .cloned()
+        .ok_or(anyhow::anyhow!("The curve has no control points"))?;
+
+    let rotation = Matrix3::from_columns(&[x_axis, y_axis, normal]);
+    let mut matrix: Matrix4<T> = rotation.to_homogeneous();
+    matrix
+        .fixed_view_mut::<3, 1>(0, 3)
+        .copy_from(&origin.coords);
+    let mut inverse: Matrix4<T> = rotation.transpose().to_homogeneous();
+    inverse
+        .fixed_view_mut::<3, 1>(0, 3)
+        .copy_from(&(-(rotation.transpose() * origin.coords)));
+
+    let local = try_flatten_curve(&curve.transformed(&inverse), T::from_f64(1e-6).unwrap())?;
+    let offset = local.offset(option)?;
+    Ok(offset
+        .into_iter()
+        .map(|c| c.elevate_dimension().transformed(&matrix))
+        .collect())
+}
+
+/// Offset the curve lying on the surface along the surface normal
+fn try_offset_on_surface<T: FloatingPoint + ArgminFloat>(
+    curve: &NurbsCurve3D<T>,
+    surface: &NurbsSurface3D<T>,
+    option: &CurveOffsetOption<T>,
+) -> anyhow::Result<Vec<CompoundCurve3D<T>>> {
+    let pts = tessellate_nurbs_curve(curve, *option.normal_tolerance())
+        .into_iter()
+        .map(|(t, p, _)| {
+            let (u, v) = surface.find_closest_parameter(&p)?;
+            let n = surface.normal_at(u, v).normalize();
+            Ok(p + n * option.distance_at(t))
+        })
+        .collect::<anyhow::Result<Vec<_>>>()?;
+    let degree = curve.degree().min(pts.len() - 1);
+    let mut res = NurbsCurve3D::try_interpolate(&pts, degree)?;
+    res.try_reduce_knots(Some(*option.knot_tolerance()))?;
+    Ok(vec![res.into()])
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+    use nalgebra::{Point3, Vector3};
+
+    use crate::{
+        curve::NurbsCurve3D,
+        offset::{CurveOffsetOption, CurveOffsetOption3D, CurveOffsetReference, Offset},
+        surface::NurbsSurface3D,
+    };
+
+    #[test]
+    fn offset_curve_on_cylinder() {
+        let circle =
+            NurbsCurve3D::<f64>::try_circle(&Point3::origin(), &Vector3::x(), &Vector3::y(), 1.)
+                .unwrap();
+        let cylinder = NurbsSurface3D::extrude(&circle, &(Vector3::z() * 2.));
+
+        // helix-like curve lying on the cylinder
+        let curve = NurbsCurve3D::try_interpolate(
+            &(0..=8)
+                .map(|i| {
+                    let t = i as f64 / 8. * std::f64::consts::PI;
+                    Point3::new(t.cos(), t.sin(), t / std::f64::consts::PI)
+                })
+                .collect::<Vec<_>>(),
+            3,
+        )
+        .unwrap();
+
+        // the normal of the cylinder may face inward depending on the parameterization
+        let (p, n) = (cylinder.point_at(0.1, 0.5), cylinder.normal_at(0.1, 0.5));
+        let sign: f64 = p.coords.xy().dot(&n.xy()).signum();
+
+        let option = CurveOffsetOption3D::new(
+            CurveOffsetReference::Surface(&cylinder),
+            CurveOffsetOption::default().with_distance(0.5 * sign),
+        );
+        let offset = curve.offset(option).unwrap();
+        assert_eq!(offset.len(), 1);
+        let (start, end) = offset[0].knots_domain();
+        offset[0].spans()[0]
+            .sample_regular_range(start, end, 16)
+            .iter()
+            .for_each(|p| {
+                assert_relative_eq!(p.coords.xy().norm(), 1.5, epsilon = 1e-2);
+            });
+    }
+}
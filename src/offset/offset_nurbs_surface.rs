@@ -0,0 +1,444 @@
+use itertools::Itertools;
+use nalgebra::{DMatrix, Point3, Vector2, Vector3};
+
+use crate::{
+    knot::KnotVector,
+    misc::{linear_algebra::interpolation_matrix, FloatingPoint},
+    surface::NurbsSurface3D,
+    tessellation::adaptive_tessellation_node::evaluate_surface,
+};
+
+/// Maximum number of refinements of the sampling grid to fit the offset surface
+const MAX_OFFSET_REFINEMENTS: usize = 5;
+
+/// A result of offsetting a NURBS surface
+/// Holds the fitted surface & the maximum deviation from the true offset measured at the sampling points.
+#[derive(Clone, Debug)]
+pub struct NurbsSurfaceOffset<T: FloatingPoint> {
+    surface: NurbsSurface3D<T>,
+    max_deviation: T,
+}
+
+impl<T: FloatingPoint> NurbsSurfaceOffset<T> {
+    pub fn surface(&self) -> &NurbsSurface3D<T> {
+        &self.surface
+    }
+
+    pub fn into_surface(self) -> NurbsSurface3D<T> {
+        self.surface
+    }
+
+    /// Get the maximum deviation of the fitted surface from the true offset
+    pub fn max_deviation(&self) -> T {
+        self.max_deviation
+    }
+}
+
+impl<T: FloatingPoint> NurbsSurface3D<T> {
+    /// Try to offset the surface along its normal by the distance
+    /// The true offset is sampled on the grid in each knot span of the parameter space, and a NURBS surface is fitted to the samples span by span.
+    /// The patches fitted on the adjacent spans are joined with C0 continuity, so the kinks of the offset at the knots with reduced continuity are kept.
+    /// At the creases (C0 knots) of the surface, the offset is not continuous, and the patches are joined by repeating the knot degree + 1 times.
+    /// The grid of each span is refined until the deviation from the true offset falls below the tolerance,
+    /// and fails if the deviation still exceeds the tolerance after the maximum number of refinements.
+    /// At the points where the normal is degenerated (e.g. the poles of a sphere), the normal is taken from the vicinity.
+    /// # Example
+    /// ```
+    /// use curvo::prelude::*;
+    /// use nalgebra::{Point3, Vector3};
+    /// use approx::assert_relative_eq;
+    /// let sphere = NurbsSurface3D::try_sphere(&Point3::origin(), &Vector3::z(), &Vector3::x(), 1.).unwrap();
+    /// let offset = sphere.try_offset(0.5, 1e-3).unwrap();
+    /// assert!(offset.max_deviation() < 1e-3);
+    ///
+    /// let surface = offset.surface();
+    /// let (u, v) = surface.knots_domain();
+    /// for i in 0..=8 {
+    ///     for j in 0..=8 {
+    ///         let s = u.0 + (u.1 - u.0) * i as f64 / 8.;
+    ///         let t = v.0 + (v.1 - v.0) * j as f64 / 8.;
+    ///         let p = surface.point_at(s, t);
+    ///         assert_relative_eq!(p.coords.norm(), 1.5, epsilon = 1e-2);
+    ///     }
+    /// }
+    /// ```
+    pub fn try_offset(&self, distance: T, tolerance: T) -> anyhow::Result<NurbsSurfaceOffset<T>> {
+        anyhow::ensure!(
+            tolerance > T::zero(),
+            "The tolerance must be greater than zero"
+        );
+
+        let (u_domain, v_domain) = self.knots_domain();
+        let u_degree = self.u_degree().max(3);
+        let v_degree = self.v_degree().max(3);
+        let u_spans = knot_spans(self.u_knots(), u_domain);
+        let v_spans = knot_spans(self.v_knots(), v_domain);
+        let mut nu = vec![u_degree + 1; u_spans.len()];
+        let mut nv = vec![v_degree + 1; v_spans.len()];
+
+        let mut max_deviation = T::zero();
+        for _ in 0..=MAX_OFFSET_REFINEMENTS {
+            let mut refine_u = vec![false; u_spans.len()];
+            let mut refine_v = vec![false; v_spans.len()];
+            max_deviation = T::zero();
+
+            let patches = u_spans
+                .iter()
+                .enumerate()
+                .map(|(i, u_span)| {
+                    v_spans
+                        .iter()
+                        .enumerate()
+                        .map(|(j, v_span)| {
+                            let (patch, deviation) = self.try_fit_offset_patch(
+                                *u_span,
+                                *v_span,
+                                (nu[i], nv[j]),
+                                (u_degree, v_degree),
+                                distance,
+                            )?;
+                            if deviation > tolerance {
+                                refine_u[i] = true;
+                                refine_v[j] = true;
+                            }
+                            max_deviation = max_deviation.max(deviation);
+                            Ok(patch)
+                        })
+                        .collect::<anyhow::Result<Vec<_>>>()
+                })
+                .collect::<anyhow::Result<Vec<_>>>()?;
+
+            if max_deviation <= tolerance {
+                return Ok(NurbsSurfaceOffset {
+                    surface: join_patches(&patches, u_degree, v_degree, tolerance),
+                    max_deviation,
+                });
+            }
+
+            // refine the grids of the spans where the deviation exceeds the tolerance
+            nu.iter_mut()
+                .zip(refine_u)
+                .chain(nv.iter_mut().zip(refine_v))
+                .filter(|(_, refine)| *refine)
+                .for_each(|(n, _)| *n = *n * 2 - 1);
+        }
+
+        anyhow::bail!(
+            "Failed to fit the offset surface within the tolerance {}: the deviation is {}",
+            tolerance,
+            max_deviation
+        )
+    }
+
+    /// Fit the offset surface on the patch of the parameter space spanned by `u_span` & `v_span`
+    /// Returns the fitted patch & its maximum deviation from the true offset.
+    fn try_fit_offset_patch(
+        &self,
+        u_span: (T, T),
+        v_span: (T, T),
+        (nu, nv): (usize, usize),
+        (u_degree, v_degree): (usize, usize),
+        distance: T,
+    ) -> anyhow::Result<(NurbsSurface3D<T>, T)> {
+        let half = T::from_f64(0.5).unwrap();
+        let inner = Vector2::new((u_span.0 + u_span.1) * half, (v_span.0 + v_span.1) * half);
+
+        // sample the true offset on the grid refined twice as the fitting grid
+        let us = regular_parameters(u_span, nu * 2 - 1);
+        let vs = regular_parameters(v_span, nv * 2 - 1);
+        let samples = us
+            .iter()
+            .map(|u| {
+                vs.iter()
+                    .map(|v| self.offset_point_at(Vector2::new(*u, *v), inner, distance))
+                    .collect_vec()
+            })
+            .collect_vec();
+
+        // fit the patch to the samples at the even indices
+        let points = samples
+            .iter()
+            .step_by(2)
+            .map(|row| row.iter().step_by(2).cloned().collect_vec())
+            .collect_vec();
+        let patch = try_fit_grid(
+            &points,
+            &us.iter().step_by(2).cloned().collect_vec(),
+            &vs.iter().step_by(2).cloned().collect_vec(),
+            u_degree,
+            v_degree,
+        )?;
+
+        // measure the deviation at all the samples
+        let deviation = us
+            .iter()
+            .enumerate()
+            .flat_map(|(i, u)| {
+                let patch = &patch;
+                let samples = &samples;
+                vs.iter()
+                    .enumerate()
+                    .map(move |(j, v)| (patch.point_at(*u, *v) - samples[i][j]).norm())
+            })
+            .fold(T::zero(), |acc, d| acc.max(d));
+
+        Ok((patch, deviation))
+    }
+
+    /// Evaluate the point offset along the normal at the given (u, v) parameter
+    /// The normal is taken slightly toward the `inner` parameter,
+    /// so that the points on a crease are offset along the normal of the side containing `inner`.
+    /// Where the normal is degenerated, it approaches further to `inner` to find the limit of the normal.
+    fn offset_point_at(&self, uv: Vector2<T>, inner: Vector2<T>, distance: T) -> Point3<T> {
+        let normal = [1e-9, 1e-6, 1e-4, 1e-2]
+            .iter()
+            .find_map(|t| {
+                let t = T::from_f64(*t).unwrap();
+                let q = evaluate_surface(self, uv + (inner - uv) * t);
+                (!q.is_normal_degenerated()).then_some(q.normal)
+            })
+            .unwrap_or(Vector3::zeros());
+        self.point_at(uv.x, uv.y) + normal * distance
+    }
+}
+
+/// Split the domain into the spans between the distinct knots
+fn knot_spans<T: FloatingPoint>(knots: &KnotVector<T>, domain: (T, T)) -> Vec<(T, T)> {
+    knots
+        .multiplicity()
+        .iter()
+        .map(|m| *m.knot())
+        .filter(|k| domain.0 <= *k && *k <= domain.1)
+        .tuple_windows()
+        .collect()
+}
+
+/// Join the grid of patches fitted on the knot spans into a single surface
+/// The adjacent patches are joined with C0 continuity if their boundaries coincide within the tolerance,
+/// otherwise the knot between them is repeated degree + 1 times to keep the gap of the offset at the crease.
+fn join_patches<T: FloatingPoint>(
+    patches: &[Vec<NurbsSurface3D<T>>],
+    u_degree: usize,
+    v_degree: usize,
+    tolerance: T,
+) -> NurbsSurface3D<T> {
+    let u_joined = patches
+        .iter()
+        .tuple_windows()
+        .map(|(c0, c1)| {
+            c0.iter().zip(c1.iter()).all(|(p0, p1)| {
+                let r0 = p0.control_points().last().unwrap();
+                let r1 = p1.control_points().first().unwrap();
+                r0.iter()
+                    .zip(r1.iter())
+                    .all(|(a, b)| (a - b).norm() <= tolerance)
+            })
+        })
+        .collect_vec();
+    let v_joined = (1..patches[0].len())
+        .map(|j| {
+            patches.iter().all(|column| {
+                let p0 = column[j - 1].control_points().iter();
+                let p1 = column[j].control_points().iter();
+                p0.zip(p1)
+                    .all(|(r0, r1)| (r0.last().unwrap() - r1.first().unwrap()).norm() <= tolerance)
+            })
+        })
+        .collect_vec();
+
+    let u_knots = join_knots(patches.iter().map(|c| c[0].u_knots()), &u_joined, u_degree);
+    let v_knots = join_knots(patches[0].iter().map(|p| p.v_knots()), &v_joined, v_degree);
+
+    let control_points = patches
+        .iter()
+        .enumerate()
+        .flat_map(|(i, column)| {
+            let skip = usize::from(i > 0 && u_joined[i - 1]);
+            let rows = column[0].control_points().len();
+            (skip..rows).map(|r| {
+                column
+                    .iter()
+                    .enumerate()
+                    .flat_map(|(j, patch)| {
+                        let skip = usize::from(j > 0 && v_joined[j - 1]);
+                        patch.control_points()[r].iter().skip(skip).cloned()
+                    })
+                    .collect_vec()
+            })
+        })
+        .collect_vec();
+
+    NurbsSurface3D::new(u_degree, v_degree, u_knots, v_knots, control_points)
+}
+
+/// Concatenate the clamped knot vectors of the adjacent patches
+/// The knot between the patches is repeated degree times if they are joined, otherwise degree + 1 times.
+fn join_knots<'a, T: FloatingPoint + 'a>(
+    knots: impl Iterator<Item = &'a KnotVector<T>>,
+    joined: &[bool],
+    degree: usize,
+) -> Vec<T> {
+    let mut concatenated = vec![];
+    for (i, knots) in knots.enumerate() {
+        if i == 0 {
+            concatenated.extend(knots.iter().cloned());
+        } else {
+            if joined[i - 1] {
+                concatenated.pop();
+            }
+            concatenated.extend(knots.iter().skip(degree + 1).cloned());
+        }
+    }
+    concatenated
+}
+
+/// Create the regularly spaced parameters in the domain
+fn regular_parameters<T: FloatingPoint>(domain: (T, T), n: usize) -> Vec<T> {
+    let div = T::from_usize(n - 1).unwrap();
+    (0..n)
+        .map(|i| domain.0 + (domain.1 - domain.0) * T::from_usize(i).unwrap() / div)
+        .collect()
+}
+
+/// Fit a non-rational surface interpolating the grid of points at the given parameters
+fn try_fit_grid<T: FloatingPoint>(
+    points: &[Vec<Point3<T>>],
+    us: &[T],
+    vs: &[T],
+    u_degree: usize,
+    v_degree: usize,
+) -> anyhow::Result<NurbsSurface3D<T>> {
+    let (nu, nv) = (us.len(), vs.len());
+    let u_degree = u_degree.min(nu - 1);
+    let v_degree = v_degree.min(nv - 1);
+    let (u_knots, mu) = interpolation_matrix(us, u_degree);
+    let (v_knots, mv) = interpolation_matrix(vs, v_degree);
+
+    // solve in the u direction for each column of the grid
+    let rhs = DMatrix::from_fn(nu, nv * 3, |i, k| points[i][k / 3][k % 3]);
+    let r = mu
+        .lu()
+        .solve(&rhs)
+        .ok_or(anyhow::anyhow!("Failed to solve the interpolation in u"))?;
+
+    // then solve in the v direction for each row of the intermediate points
+    let rhs = DMatrix::from_fn(nv, nu * 3, |j, k| r[(k / 3, j * 3 + k % 3)]);
+    let q = mv
+        .lu()
+        .solve(&rhs)
+        .ok_or(anyhow::anyhow!("Failed to solve the interpolation in v"))?;
+
+    let control_points = (0..nu)
+        .map(|i| {
+            (0..nv)
+                .map(|j| {
+                    Point3::new(q[(j, i * 3)], q[(j, i * 3 + 1)], q[(j, i * 3 + 2)])
+                        .to_homogeneous()
+                        .into()
+                })
+                .collect_vec()
+        })
+        .collect_vec();
+
+    Ok(NurbsSurface3D::new(
+        u_degree,
+        v_degree,
+        u_knots,
+        v_knots,
+        control_points,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+    use nalgebra::{Point3, Vector3};
+
+    use crate::{curve::NurbsCurve3D, surface::NurbsSurface3D};
+
+    #[test]
+    fn offset_plane() {
+        let plane = NurbsSurface3D::<f64>::plane(Point3::origin(), Vector3::x(), Vector3::y());
+        let offset = plane.try_offset(0.5, 1e-6).unwrap();
+        assert!(offset.max_deviation() < 1e-6);
+        let surface = offset.surface();
+        let (u, v) = surface.knots_domain();
+        assert_relative_eq!(
+            surface.point_at(u.0, v.0),
+            Point3::new(-1., -1., 0.5),
+            epsilon = 1e-8
+        );
+        assert_relative_eq!(
+            surface.point_at(u.1, v.1),
+            Point3::new(1., 1., 0.5),
+            epsilon = 1e-8
+        );
+    }
+
+    #[test]
+    fn offset_cylinder_inward() {
+        let circle =
+            NurbsCurve3D::<f64>::try_circle(&Point3::origin(), &Vector3::x(), &Vector3::y(), 2.)
+                .unwrap();
+        let cylinder = NurbsSurface3D::extrude(&circle, &(Vector3::z() * 3.));
+
+        // orient the distance toward the axis
+        let (p, n) = (cylinder.point_at(0.1, 0.5), cylinder.normal_at(0.1, 0.5));
+        let sign: f64 = -p.coords.xy().dot(&n.xy()).signum();
+
+        let offset = cylinder.try_offset(0.5 * sign, 1e-4).unwrap();
+        assert!(offset.max_deviation() < 1e-4);
+        let surface = offset.surface();
+        let (u, v) = surface.knots_domain();
+        for i in 0..=16 {
+            let s = u.0 + (u.1 - u.0) * i as f64 / 16.;
+            let t = v.0 + (v.1 - v.0) * 0.3;
+            let p = surface.point_at(s, t);
+            assert_relative_eq!(p.coords.xy().norm(), 1.5, epsilon = 1e-3);
+        }
+    }
+
+    #[test]
+    fn offset_folded_surface_at_crease() {
+        let polyline = NurbsCurve3D::<f64>::polyline(
+            &[
+                Point3::origin(),
+                Point3::new(1., 0., 0.),
+                Point3::new(1., 1., 0.),
+            ],
+            false,
+        );
+        let folded = NurbsSurface3D::extrude(&polyline, &Vector3::z());
+        let crease = folded.v_knots().as_slice()[2];
+
+        let offset = folded.try_offset(0.1, 1e-6).unwrap();
+        assert!(offset.max_deviation() < 1e-6);
+
+        // the offsets of the two faces are separated at the crease
+        let surface = offset.surface();
+        assert_eq!(
+            surface.v_knots().iter().filter(|k| **k == crease).count(),
+            surface.v_degree() + 1
+        );
+
+        let (u, v) = surface.knots_domain();
+        for i in 0..=16 {
+            let s = u.0 + (u.1 - u.0) * 0.3;
+            let t = v.0 + (v.1 - v.0) * i as f64 / 16.;
+            let p = surface.point_at(s, t);
+            if t < crease {
+                assert_relative_eq!(p.y.abs(), 0.1, epsilon = 1e-6);
+            } else {
+                assert_relative_eq!((p.x - 1.).abs(), 0.1, epsilon = 1e-6);
+            }
+        }
+    }
+
+    #[test]
+    fn offset_fails_beyond_tolerance() {
+        let sphere =
+            NurbsSurface3D::<f64>::try_sphere(&Point3::origin(), &Vector3::z(), &Vector3::x(), 1.)
+                .unwrap();
+        assert!(sphere.try_offset(0.5, 1e-12).is_err());
+    }
+}
@@ -0,0 +1,412 @@
+use argmin::core::ArgminFloat;
+use itertools::Itertools;
+use nalgebra::{center, Const};
+
+use crate::{
+    boolean::degeneracies::close_curve_gaps,
+    curve::NurbsCurve2D,
+    misc::{FloatingPoint, Invertible},
+    offset::{
+        offset_nurbs_curve::tessellate_nurbs_curve, CurveOffsetCornerType, CurveOffsetOption,
+        Offset,
+    },
+    prelude::{BoundingBox, CurveIntersectionSolverOptions, HasIntersection, Intersects},
+    region::{CompoundCurve2D, Region},
+    split::Split,
+    tessellation::Tessellation,
+};
+
+impl<'a, T> Offset<'a, T> for Region<T>
+where
+    T: FloatingPoint + ArgminFloat,
+{
+    type Output = anyhow::Result<Vec<Region<T>>>;
+    type Option = CurveOffsetOption<T>;
+
+    /// Offset the region by a given option
+    /// A positive distance grows the region (outset) & a negative distance shrinks the region (inset).
+    /// The exterior & the interiors are offset consistently, then the self-intersecting loops are split,
+    /// the collapsed loops are removed, and the overlapping loops are merged by the boolean operations.
+    /// # Example
+    /// ```
+    /// use curvo::prelude::*;
+    /// use nalgebra::{Point2, Vector2};
+    /// let square = NurbsCurve2D::polyline(&[
+    ///     Point2::new(0., 0.),
+    ///     Point2::new(4., 0.),
+    ///     Point2::new(4., 4.),
+    ///     Point2::new(0., 4.),
+    ///     Point2::new(0., 0.),
+    /// ], true);
+    /// let hole = NurbsCurve2D::try_circle(&Point2::new(2., 2.), &Vector2::x(), &Vector2::y(), 0.5).unwrap();
+    /// let region = Region::new(square.into(), vec![hole.into()]);
+    ///
+    /// // pocketing: inset the region repeatedly until it vanishes
+    /// let option = CurveOffsetOption::default()
+    ///     .with_distance(-0.4)
+    ///     .with_corner_type(CurveOffsetCornerType::Round);
+    /// let mut regions = vec![region];
+    /// let mut passes = 0;
+    /// while !regions.is_empty() {
+    ///     regions = regions
+    ///         .iter()
+    ///         .map(|r| r.offset(option.clone()))
+    ///         .collect::<anyhow::Result<Vec<_>>>()
+    ///         .unwrap()
+    ///         .into_iter()
+    ///         .flatten()
+    ///         .collect();
+    ///     regions.iter().for_each(|r| assert!(r.exterior().is_closed(None)));
+    ///     passes += 1;
+    /// }
+    /// assert_eq!(passes, 3);
+    /// ```
+    fn offset(&'a self, option: Self::Option) -> Self::Output {
+        anyhow::ensure!(
+            !matches!(option.corner_type(), CurveOffsetCornerType::None),
+            "The corner type must not be None to offset the closed boundaries of the region"
+        );
+
+        // offset the counter-clockwise exterior & the clockwise interiors,
+        // so that the right side of the boundaries faces outward from the region
+        let exteriors = offset_loop(self.exterior(), true, &option)?
+            .into_iter()
+            .map(Region::from)
+            .collect_vec();
+        let holes = self
+            .interiors()
+            .iter()
+            .map(|interior| offset_loop(interior, false, &option))
+            .collect::<anyhow::Result<Vec<_>>>()?
+            .into_iter()
+            .flatten()
+            .map(|mut hole| {
+                hole.invert();
+                // realign the knot vectors of the spans reversed by the inversion
+                Region::from(CompoundCurve2D::new_unchecked_aligned(hole.into_spans()))
+            })
+            .collect_vec();
+
+        let regions = Region::union_all(&exteriors, option.intersection_option().clone())?
+            .iter()
+            .map(|exterior| {
+                if holes.is_empty() {
+                    Ok(vec![exterior.clone()])
+                } else {
+                    exterior.difference_many(&holes, option.intersection_option().clone())
+                }
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+        Ok(regions.into_iter().flatten().collect())
+    }
+}
+
+/// Offset the closed boundary & return the simple loops keeping the orientation of the boundary
+/// The loops with the inverted orientation, which are produced by collapsing, are removed.
+fn offset_loop<T: FloatingPoint + ArgminFloat>(
+    boundary: &CompoundCurve2D<T>,
+    ccw: bool,
+    option: &CurveOffsetOption<T>,
+) -> anyhow::Result<Vec<CompoundCurve2D<T>>> {
+    let mut boundary = boundary.clone();
+    if (signed_area(&boundary) > T::zero()) != ccw {
+        boundary.invert();
+    }
+
+    let eps = T::from_f64(1e-8).unwrap();
+    let loops = boundary
+        .offset(option.clone())?
+        .into_iter()
+        .map(|l| split_self_intersections(l, option.intersection_option()))
+        .collect::<anyhow::Result<Vec<_>>>()?;
+
+    let mut res = vec![];
+    for l in loops.into_iter().flatten().map(close_curve_gaps) {
+        let area = signed_area(&l);
+        let oriented = if ccw { area > eps } else { area < -eps };
+        if oriented && keeps_distance(&l, &boundary, option) {
+            res.push(l);
+        }
+    }
+    Ok(res)
+}
+
+/// Check if the loop keeps the offset distance from the boundary
+/// The loops closer to the boundary than the distance are the invalid parts of the raw offset,
+/// which may keep the orientation of the boundary (e.g. an inverted square from a collapsed hole).
+/// The distance is measured to the tessellated boundary, so the deviation of the tessellation from the boundary
+/// & the knot tolerance of the offset curves are allowed.
+fn keeps_distance<T: FloatingPoint>(
+    l: &CompoundCurve2D<T>,
+    boundary: &CompoundCurve2D<T>,
+    option: &CurveOffsetOption<T>,
+) -> bool {
+    let half = T::from_f64(0.5).unwrap();
+
+    // approximate the boundary by the line segments with the parameters
+    let segments = boundary
+        .spans()
+        .iter()
+        .flat_map(|span| {
+            tessellate_nurbs_curve(span, *option.normal_tolerance())
+                .into_iter()
+                .map(|(t, p, _)| (t, p))
+                .tuple_windows()
+                .map(|((t0, p0), (t1, p1))| {
+                    let sagitta = (span.point_at((t0 + t1) * half) - center(&p0, &p1)).norm();
+                    (t0, p0, t1, p1, sagitta)
+                })
+                .collect_vec()
+        })
+        .collect_vec();
+    let sagitta = segments.iter().fold(T::zero(), |acc, s| acc.max(s.4));
+    let tolerance = sagitta + *option.knot_tolerance();
+
+    l.spans().iter().all(|span| {
+        let (start, end) = span.knots_domain();
+        let p = span.point_at((start + end) * half);
+        let closest = segments
+            .iter()
+            .map(|(t0, p0, t1, p1, _)| {
+                let d = p1 - p0;
+                let l2 = d.norm_squared();
+                let r = if l2 > T::zero() {
+                    ((p - p0).dot(&d) / l2).clamp(T::zero(), T::one())
+                } else {
+                    T::zero()
+                };
+                ((p0 + d * r - p).norm(), *t0 + (*t1 - *t0) * r)
+            })
+            .min_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(std::cmp::Ordering::Equal));
+        match closest {
+            Some((d, t)) => d >= option.distance_at(t).abs() - tolerance,
+            None => true,
+        }
+    })
+}
+
+/// Compute the signed area of the closed curve (positive if counter-clockwise)
+fn signed_area<T: FloatingPoint>(curve: &CompoundCurve2D<T>) -> T {
+    let pts = curve.tessellate(None);
+    let twice = pts
+        .iter()
+        .circular_tuple_windows()
+        .fold(T::zero(), |acc, (p0, p1)| acc + p0.x * p1.y - p1.x * p0.y);
+    twice * T::from_f64(0.5).unwrap()
+}
+
+/// Split the closed curve into the simple loops at the self-intersections
+fn split_self_intersections<T: FloatingPoint + ArgminFloat>(
+    curve: CompoundCurve2D<T>,
+    option: &Option<CurveIntersectionSolverOptions<T>>,
+) -> anyhow::Result<Vec<CompoundCurve2D<T>>> {
+    let spans = explode_spans(curve);
+    let n = spans.len();
+    let boxes = spans
+        .iter()
+        .map(BoundingBox::<T, Const<2>>::from)
+        .collect_vec();
+
+    // find the first self-intersection between the non-adjacent spans
+    let mut crossing = None;
+    'search: for i in 0..n {
+        for j in (i + 2)..n {
+            if (i == 0 && j == n - 1) || !boxes[i].intersects(&boxes[j], None) {
+                continue;
+            }
+            let it = spans[i].find_intersection(&spans[j], option.clone())?;
+            if let Some(it) = it.first() {
+                crossing = Some((i, it.a().1, j, it.b().1));
+                break 'search;
+            }
+        }
+    }
+
+    let (i, ti, j, tj) = match crossing {
+        Some(c) => c,
+        None => return Ok(vec![CompoundCurve2D::new_unchecked_aligned(spans)]),
+    };
+
+    let (head_i, tail_i) = split_span(&spans[i], ti)?;
+    let (head_j, tail_j) = split_span(&spans[j], tj)?;
+
+    // the loop between the crossing & the rest of the curve
+    let inner = tail_i
+        .into_iter()
+        .chain(spans[(i + 1)..j].iter().cloned())
+        .chain(head_j)
+        .collect_vec();
+    let outer = tail_j
+        .into_iter()
+        .chain(spans[(j + 1)..].iter().cloned())
+        .chain(spans[..i].iter().cloned())
+        .chain(head_i)
+        .collect_vec();
+
+    let loops = [inner, outer]
+        .into_iter()
+        .filter(|spans| !spans.is_empty())
+        .map(|spans| {
+            split_self_intersections(CompoundCurve2D::new_unchecked_aligned(spans), option)
+        })
+        .collect::<anyhow::Result<Vec<_>>>()?;
+    Ok(loops.into_iter().flatten().collect())
+}
+
+/// Explode the polylines in the curve into the line segments to find the self-intersections in each polyline
+fn explode_spans<T: FloatingPoint>(curve: CompoundCurve2D<T>) -> Vec<NurbsCurve2D<T>> {
+    let eps = T::from_f64(1e-10).unwrap();
+    curve
+        .into_spans()
+        .into_iter()
+        .flat_map(|span| {
+            if span.degree() == 1 {
+                span.dehomogenized_control_points()
+                    .windows(2)
+                    .filter(|w| (w[1] - w[0]).norm() > eps)
+                    .map(|w| NurbsCurve2D::polyline(w, false))
+                    .collect_vec()
+            } else {
+                vec![span]
+            }
+        })
+        .collect()
+}
+
+/// Split the span at the parameter & return the pieces before and after the parameter
+/// The piece degenerated into a point is omitted.
+#[allow(clippy::type_complexity)]
+fn split_span<T: FloatingPoint>(
+    span: &NurbsCurve2D<T>,
+    t: T,
+) -> anyhow::Result<(Option<NurbsCurve2D<T>>, Option<NurbsCurve2D<T>>)> {
+    let (start, end) = span.knots_domain();
+    let eps = (end - start) * T::from_f64(1e-8).unwrap();
+    if t <= start + eps {
+        Ok((None, Some(span.clone())))
+    } else if t >= end - eps {
+        Ok((Some(span.clone()), None))
+    } else {
+        let (head, tail) = span.try_split(t)?;
+        Ok((Some(head), Some(tail)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nalgebra::Point2;
+
+    use crate::{
+        curve::NurbsCurve2D,
+        offset::{CurveOffsetCornerType, CurveOffsetOption, Offset},
+        prelude::CurveIntersectionSolverOptions,
+        region::Region,
+    };
+
+    #[test]
+    fn inset_dumbbell_splits_into_two() {
+        // two squares connected by a thin bridge
+        let dumbbell = NurbsCurve2D::polyline(
+            &[
+                Point2::new(0., 0.),
+                Point2::new(2., 0.),
+                Point2::new(2., 0.8),
+                Point2::new(3., 0.8),
+                Point2::new(3., 0.),
+                Point2::new(5., 0.),
+                Point2::new(5., 2.),
+                Point2::new(3., 2.),
+                Point2::new(3., 1.2),
+                Point2::new(2., 1.2),
+                Point2::new(2., 2.),
+                Point2::new(0., 2.),
+                Point2::new(0., 0.),
+            ],
+            true,
+        );
+        let region: Region<f64> = dumbbell.into();
+        let option = CurveOffsetOption::default().with_corner_type(CurveOffsetCornerType::Sharp);
+
+        let inset = region.offset(option.clone().with_distance(-0.1)).unwrap();
+        assert_eq!(inset.len(), 1);
+
+        let inset = region.offset(option.clone().with_distance(-0.4)).unwrap();
+        assert_eq!(inset.len(), 2);
+
+        let inset = region.offset(option.clone().with_distance(-1.2)).unwrap();
+        assert!(inset.is_empty());
+    }
+
+    #[test]
+    fn outset_closes_holes() {
+        let square = NurbsCurve2D::polyline(
+            &[
+                Point2::new(0., 0.),
+                Point2::new(3., 0.),
+                Point2::new(3., 3.),
+                Point2::new(0., 3.),
+                Point2::new(0., 0.),
+            ],
+            true,
+        );
+        let hole = NurbsCurve2D::polyline(
+            &[
+                Point2::new(1., 1.),
+                Point2::new(2., 1.),
+                Point2::new(2., 2.),
+                Point2::new(1., 2.),
+                Point2::new(1., 1.),
+            ],
+            true,
+        );
+        let region = Region::new(square.into(), vec![hole.into()]);
+        let option = CurveOffsetOption::default().with_corner_type(CurveOffsetCornerType::Round);
+
+        let outset = region.offset(option.clone().with_distance(0.2)).unwrap();
+        assert_eq!(outset.len(), 1);
+        assert_eq!(outset[0].interiors().len(), 1);
+
+        let outset = region.offset(option.clone().with_distance(0.6)).unwrap();
+        assert_eq!(outset.len(), 1);
+        assert!(outset[0].interiors().is_empty());
+    }
+
+    #[test]
+    fn inset_merges_holes() {
+        let rectangle = |x0: f64, y0: f64, x1: f64, y1: f64| {
+            NurbsCurve2D::polyline(
+                &[
+                    Point2::new(x0, y0),
+                    Point2::new(x1, y0),
+                    Point2::new(x1, y1),
+                    Point2::new(x0, y1),
+                    Point2::new(x0, y0),
+                ],
+                true,
+            )
+        };
+        let region = Region::new(
+            rectangle(-1., -1., 7., 4.).into(),
+            vec![
+                rectangle(1., 1., 2.5, 2.).into(),
+                rectangle(3.5, 1.2, 5., 1.8).into(),
+            ],
+        );
+        let option = CurveOffsetOption::default()
+            .with_corner_type(CurveOffsetCornerType::Round)
+            .with_intersection_option(Some(CurveIntersectionSolverOptions {
+                minimum_distance: 1e-4,
+                ..Default::default()
+            }));
+
+        // the holes grow apart
+        let inset = region.offset(option.clone().with_distance(-0.2)).unwrap();
+        assert_eq!(inset.len(), 1);
+        assert_eq!(inset[0].interiors().len(), 2);
+
+        // the holes grow into one
+        let inset = region.offset(option.clone().with_distance(-0.6)).unwrap();
+        assert_eq!(inset.len(), 1);
+        assert_eq!(inset[0].interiors().len(), 1);
+    }
+}
@@ -3,12 +3,12 @@ use std::cmp::Ordering;
 use argmin::core::ArgminFloat;
 use itertools::Itertools;
 use nalgebra::{
-    allocator::Allocator, Const, DefaultAllocator, DimName, DimNameDiff, DimNameSub, OMatrix,
-    OPoint, OVector, U1,
+    allocator::Allocator, Const, DefaultAllocator, DimName, DimNameAdd, DimNameDiff, DimNameSub,
+    OMatrix, OPoint, OVector, U1,
 };
 
 use crate::{
-    curve::NurbsCurve,
+    curve::{CurveEnd, ExtendType, NurbsCurve},
     misc::{FloatingPoint, Invertible, Transformable},
 };
 
@@ -264,6 +264,49 @@ where
         Ok(total)
     }
 
+    /// Try to extend the first or last span of the compound curve beyond the end by the length
+    /// # Example
+    /// ```
+    /// use curvo::prelude::*;
+    /// use nalgebra::{Point2, Vector2};
+    /// use std::f64::consts::PI;
+    /// use approx::assert_relative_eq;
+    /// let compound = CompoundCurve::try_new(vec![
+    ///     NurbsCurve2D::polyline(&[Point2::new(-1., -1.), Point2::new(-1., 0.)], false),
+    ///     NurbsCurve2D::try_arc(&Point2::origin(), &Vector2::x(), &Vector2::y(), 1., PI, PI * 1.5).unwrap(),
+    /// ]).unwrap();
+    ///
+    /// let extended = compound.try_extend(CurveEnd::Start, 1., ExtendType::Line).unwrap();
+    /// let extended = extended.try_extend(CurveEnd::End, 1., ExtendType::Line).unwrap();
+    /// assert_eq!(extended.spans().len(), 2);
+    ///
+    /// let (start, end) = extended.knots_domain();
+    /// assert_relative_eq!(extended.point_at(start), Point2::new(-1., -2.), epsilon = 1e-8);
+    /// assert_relative_eq!(extended.point_at(end), Point2::new(1., -1.), epsilon = 1e-8);
+    /// assert_relative_eq!(extended.try_length().unwrap(), 3. + PI / 2., epsilon = 1e-6);
+    /// ```
+    pub fn try_extend(
+        &self,
+        end: CurveEnd,
+        length: T,
+        extend_type: ExtendType,
+    ) -> anyhow::Result<Self>
+    where
+        D: DimNameSub<U1>,
+        <D as DimNameSub<U1>>::Output: DimNameAdd<U1>,
+        DefaultAllocator: Allocator<DimNameDiff<D, U1>>,
+        DefaultAllocator: Allocator<<<D as DimNameSub<U1>>::Output as DimNameAdd<U1>>::Output>,
+    {
+        anyhow::ensure!(!self.spans.is_empty(), "The compound curve has no span");
+        let index = match end {
+            CurveEnd::Start => 0,
+            CurveEnd::End => self.spans.len() - 1,
+        };
+        let mut spans = self.spans.clone();
+        spans[index] = spans[index].try_extend(end, length, extend_type)?;
+        Ok(Self::new_unchecked(spans))
+    }
+
     /// Find the closest point on the curve to a given point
     /// # Example
     /// ```
@@ -1,7 +1,9 @@
 use nalgebra::{Const, OMatrix, U3};
 pub mod compound_curve;
 mod curve_direction;
+pub mod planar_region;
 pub use compound_curve::*;
+pub use planar_region::*;
 
 use crate::{
     curve::NurbsCurve,
@@ -0,0 +1,267 @@
+use nalgebra::{Matrix3, Matrix4, Point3, Vector3};
+
+use crate::{
+    dimension::try_flatten_curve,
+    misc::{FloatingPoint, Invertible, Plane, Transformable},
+    prelude::ElevateDimension,
+};
+
+use super::{CompoundCurve2D, CompoundCurve3D, Region};
+
+/// A region lying on an arbitrary plane in 3D space.
+/// The region is held in the local 2D coordinates of the plane frame,
+/// so that the boolean operations for 2D regions can be applied to the planar 3D regions.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PlanarRegion<T: FloatingPoint> {
+    origin: Point3<T>,
+    x_axis: Vector3<T>,
+    y_axis: Vector3<T>,
+    region: Region<T>,
+}
+
+impl<T: FloatingPoint> PlanarRegion<T> {
+    /// Create a new planar region from the plane frame & the region in local 2D coordinates
+    /// The axes are orthonormalized with respect to the x axis
+    pub fn new(
+        origin: Point3<T>,
+        x_axis: Vector3<T>,
+        y_axis: Vector3<T>,
+        region: Region<T>,
+    ) -> Self {
+        let x_axis = x_axis.normalize();
+        let normal = x_axis.cross(&y_axis).normalize();
+        let y_axis = normal.cross(&x_axis);
+        Self {
+            origin,
+            x_axis,
+            y_axis,
+            region,
+        }
+    }
+
+    /// Try to create a planar region from the 3D boundary curves
+    /// The plane frame is fitted to the control points of the exterior curve,
+    /// and the normal is oriented so that the exterior winds counter-clockwise in the local coordinates.
+    /// Returns an error if the curves are not lying on the same plane.
+    /// # Example
+    /// ```
+    /// use curvo::prelude::*;
+    /// use nalgebra::{Point3, Vector3};
+    /// use approx::assert_relative_eq;
+    ///
+    /// // unit square on the tilted plane
+    /// let tilt = Vector3::new(0., 1., 1.).normalize();
+    /// let square = NurbsCurve3D::polyline(&[
+    ///     Point3::origin(),
+    ///     Point3::origin() + Vector3::x(),
+    ///     Point3::origin() + Vector3::x() + tilt,
+    ///     Point3::origin() + tilt,
+    ///     Point3::origin(),
+    /// ], true);
+    /// let region = PlanarRegion::try_new(square.into(), vec![]).unwrap();
+    /// assert_relative_eq!(region.normal(), Vector3::x().cross(&tilt), epsilon = 1e-8);
+    /// assert!(region.exterior().is_closed(None));
+    /// ```
+    pub fn try_new(
+        exterior: CompoundCurve3D<T>,
+        interiors: Vec<CompoundCurve3D<T>>,
+    ) -> anyhow::Result<Self> {
+        let points = exterior
+            .spans()
+            .iter()
+            .flat_map(|span| span.dehomogenized_control_points())
+            .collect::<Vec<_>>();
+        anyhow::ensure!(points.len() >= 3, "Too few control points to fit a plane");
+
+        let n = T::from_usize(points.len()).unwrap();
+        let center = points
+            .iter()
+            .fold(Vector3::zeros(), |acc, p| acc + p.coords)
+            / n;
+        let center = Point3::from(center);
+
+        // Newell's method to compute the normal oriented by the winding of the exterior
+        let normal = points
+            .iter()
+            .zip(points.iter().cycle().skip(1))
+            .fold(Vector3::zeros(), |acc, (p0, p1)| {
+                acc + (p0 - center).cross(&(p1 - center))
+            });
+        let norm = normal.norm();
+        anyhow::ensure!(
+            norm > T::default_epsilon(),
+            "Failed to fit a plane to the exterior curve"
+        );
+        let normal = normal / norm;
+
+        // x axis is directed to the farthest control point from the center
+        let x_axis = points
+            .iter()
+            .map(|p| {
+                let d = p - center;
+                d - normal * d.dot(&normal)
+            })
+            .max_by(|a, b| {
+                a.norm_squared()
+                    .partial_cmp(&b.norm_squared())
+                    .unwrap_or(std::cmp::Ordering::Equal)
+            })
+            .unwrap();
+        let y_axis = normal.cross(&x_axis);
+
+        Self::try_from_frame(center, x_axis, y_axis, exterior, interiors)
+    }
+
+    /// Try to create a planar region from the plane frame & the 3D boundary curves
+    /// Returns an error if the curves are not lying on the plane.
+    pub fn try_from_frame(
+        origin: Point3<T>,
+        x_axis: Vector3<T>,
+        y_axis: Vector3<T>,
+        exterior: CompoundCurve3D<T>,
+        interiors: Vec<CompoundCurve3D<T>>,
+    ) -> anyhow::Result<Self> {
+        let frame = Self::new(
+            origin,
+            x_axis,
+            y_axis,
+            Region::new(CompoundCurve2D::new_unchecked(vec![]), vec![]),
+        );
+        let exterior = frame.try_to_local(&exterior)?;
+        let interiors = interiors
+            .iter()
+            .map(|interior| frame.try_to_local(interior))
+            .collect::<anyhow::Result<Vec<_>>>()?;
+        Ok(Self {
+            region: Region::new(exterior, interiors),
+            ..frame
+        })
+    }
+
+    pub fn origin(&self) -> &Point3<T> {
+        &self.origin
+    }
+
+    pub fn x_axis(&self) -> &Vector3<T> {
+        &self.x_axis
+    }
+
+    pub fn y_axis(&self) -> &Vector3<T> {
+        &self.y_axis
+    }
+
+    /// Get the normal of the plane
+    pub fn normal(&self) -> Vector3<T> {
+        self.x_axis.cross(&self.y_axis)
+    }
+
+    /// Get the plane on which the region lies
+    pub fn plane(&self) -> Plane<T> {
+        let normal = self.normal();
+        Plane::new(normal, -normal.dot(&self.origin.coords))
+    }
+
+    /// Get the region in the local 2D coordinates
+    pub fn region(&self) -> &Region<T> {
+        &self.region
+    }
+
+    pub fn into_region(self) -> Region<T> {
+        self.region
+    }
+
+    /// Get the matrix to transform the local coordinates to the world coordinates
+    pub fn matrix(&self) -> Matrix4<T> {
+        Matrix4::from_columns(&[
+            self.x_axis.to_homogeneous(),
+            self.y_axis.to_homogeneous(),
+            self.normal().to_homogeneous(),
+            self.origin.to_homogeneous(),
+        ])
+    }
+
+    /// Get the exterior curve in the world coordinates
+    pub fn exterior(&self) -> CompoundCurve3D<T> {
+        self.to_world(self.region.exterior())
+    }
+
+    /// Get the interior curves in the world coordinates
+    pub fn interiors(&self) -> Vec<CompoundCurve3D<T>> {
+        self.region
+            .interiors()
+            .iter()
+            .map(|interior| self.to_world(interior))
+            .collect()
+    }
+
+    /// Create a planar region sharing the plane frame with the given region in local 2D coordinates
+    pub fn with_region(&self, region: Region<T>) -> Self {
+        Self {
+            origin: self.origin,
+            x_axis: self.x_axis,
+            y_axis: self.y_axis,
+            region,
+        }
+    }
+
+    /// Try to map the region of the other planar region into the local 2D coordinates of this plane frame
+    /// Returns an error if the planes are not coincident within the tolerance.
+    pub fn try_map_region(&self, other: &Self, tolerance: T) -> anyhow::Result<Region<T>> {
+        let m = self.inverse_matrix() * other.matrix();
+        anyhow::ensure!(
+            m[(2, 0)].abs() < tolerance
+                && m[(2, 1)].abs() < tolerance
+                && m[(2, 3)].abs() < tolerance,
+            "The regions are not lying on the same plane"
+        );
+
+        let mut transform = Matrix3::identity();
+        transform
+            .fixed_view_mut::<2, 2>(0, 0)
+            .copy_from(&m.fixed_view::<2, 2>(0, 0));
+        transform
+            .fixed_view_mut::<2, 1>(0, 2)
+            .copy_from(&m.fixed_view::<2, 1>(0, 3));
+        let mut region = other.region.transformed(&transform);
+
+        // the planes facing the opposite direction mirror the region
+        if transform.determinant() < T::zero() {
+            region.invert();
+        }
+        Ok(region)
+    }
+
+    /// Get the matrix to transform the world coordinates to the local coordinates
+    fn inverse_matrix(&self) -> Matrix4<T> {
+        let rotation =
+            Matrix3::from_columns(&[self.x_axis, self.y_axis, self.normal()]).transpose();
+        let translation = -(rotation * self.origin.coords);
+        let mut m = rotation.to_homogeneous();
+        m.fixed_view_mut::<3, 1>(0, 3).copy_from(&translation);
+        m
+    }
+
+    /// Map the 2D curve in the local coordinates to the world coordinates
+    fn to_world(&self, curve: &CompoundCurve2D<T>) -> CompoundCurve3D<T> {
+        curve.elevate_dimension().transformed(&self.matrix())
+    }
+
+    /// Try to map the 3D curve to the local 2D coordinates
+    fn try_to_local(&self, curve: &CompoundCurve3D<T>) -> anyhow::Result<CompoundCurve2D<T>> {
+        let inv = self.inverse_matrix();
+        let tolerance = T::from_f64(1e-6).unwrap();
+        let spans = curve
+            .spans()
+            .iter()
+            .map(|span| try_flatten_curve(&span.transformed(&inv), tolerance))
+            .collect::<anyhow::Result<Vec<_>>>()?;
+        Ok(CompoundCurve2D::new_unchecked(spans))
+    }
+}
+
+impl<T: FloatingPoint> From<Region<T>> for PlanarRegion<T> {
+    /// Place the 2D region on the xy plane
+    fn from(region: Region<T>) -> Self {
+        Self::new(Point3::origin(), Vector3::x(), Vector3::y(), region)
+    }
+}
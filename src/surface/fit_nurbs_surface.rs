@@ -0,0 +1,524 @@
+use itertools::Itertools;
+use nalgebra::{
+    allocator::Allocator, DMatrix, DVector, DefaultAllocator, DimName, DimNameAdd, DimNameDiff,
+    DimNameSub, OPoint, U1,
+};
+
+use crate::{
+    curve::KnotStyle,
+    knot::KnotVector,
+    misc::{
+        linear_algebra::{
+            basis_matrix, try_interpolate_columns, try_solve_banded_cholesky,
+            try_solve_least_squares,
+        },
+        transpose, FloatingPoint,
+    },
+    surface::NurbsSurface,
+};
+
+/// Weight of the smoothing term relative to the fitting term in the scattered points approximation
+/// The term keeps the control points without nearby points well defined.
+const SCATTERED_SMOOTHING: f64 = 1e-6;
+
+impl<T: FloatingPoint, D: DimName> NurbsSurface<T, D>
+where
+    D: DimNameSub<U1>,
+    <D as DimNameSub<U1>>::Output: DimNameAdd<U1>,
+    DefaultAllocator: Allocator<D>,
+    DefaultAllocator: Allocator<DimNameDiff<D, U1>>,
+    DefaultAllocator: Allocator<<<D as DimNameSub<U1>>::Output as DimNameAdd<U1>>::Output>,
+{
+    /// Try to create a surface interpolating the grid of points
+    /// The grid is indexed as `grid[u][v]` like the control points of the surface,
+    /// and the parameters of the points are averaged over the rows & columns of the grid parameterized by the knot style.
+    /// # Example
+    /// ```
+    /// use curvo::prelude::*;
+    /// use nalgebra::Point3;
+    /// use approx::assert_relative_eq;
+    ///
+    /// let grid = (0..5)
+    ///     .map(|i| {
+    ///         (0..4)
+    ///             .map(|j| {
+    ///                 let (x, y) = (i as f64, j as f64);
+    ///                 Point3::new(x, y, (x * 0.7).sin() * (y * 0.5).cos())
+    ///             })
+    ///             .collect()
+    ///     })
+    ///     .collect::<Vec<Vec<_>>>();
+    /// let surface = NurbsSurface3D::try_interpolate(&grid, 3, 2, KnotStyle::Chordal).unwrap();
+    /// assert_eq!(surface.u_degree(), 3);
+    /// assert_eq!(surface.v_degree(), 2);
+    ///
+    /// // the corners of the surface are the corners of the grid
+    /// let ((u0, u1), (v0, v1)) = surface.knots_domain();
+    /// assert_relative_eq!(surface.point_at(u0, v0), grid[0][0], epsilon = 1e-8);
+    /// assert_relative_eq!(surface.point_at(u1, v1), grid[4][3], epsilon = 1e-8);
+    ///
+    /// // every point of the grid lies on the surface
+    /// for row in grid.iter() {
+    ///     for p in row.iter() {
+    ///         let (u, v) = surface.find_closest_parameter(p).unwrap();
+    ///         assert!((surface.point_at(u, v) - p).norm() < 1e-4);
+    ///     }
+    /// }
+    /// ```
+    pub fn try_interpolate(
+        grid: &[Vec<OPoint<T, DimNameDiff<D, U1>>>],
+        u_degree: usize,
+        v_degree: usize,
+        knot_style: KnotStyle,
+    ) -> anyhow::Result<Self> {
+        let (nu, nv) = try_grid_size(grid)?;
+        anyhow::ensure!(
+            nu > u_degree && nv > v_degree,
+            "Too few points ({} x {}) for the degrees ({}, {})",
+            nu,
+            nv,
+            u_degree,
+            v_degree
+        );
+
+        let (us, vs) = grid_parameters(grid, &knot_style);
+        let points = homogenize_grid(grid);
+
+        // interpolate in the u direction for each column, then in the v direction for each row
+        let (u_knots, columns) = try_interpolate_columns(&transpose(&points), &us, u_degree)?;
+        let (v_knots, control_points) =
+            try_interpolate_columns(&transpose(&columns), &vs, v_degree)?;
+
+        Ok(Self::new(
+            u_degree,
+            v_degree,
+            u_knots,
+            v_knots,
+            control_points,
+        ))
+    }
+
+    /// Try to create a surface approximating the grid of points in the least squares sense
+    /// The surface has `u_count` x `v_count` control points, which must not exceed the size of the grid.
+    /// The grid is indexed as `grid[u][v]` & parameterized by the knot style as in `try_interpolate`.
+    /// # Example
+    /// ```
+    /// use curvo::prelude::*;
+    /// use nalgebra::Point3;
+    ///
+    /// // a noisy paraboloid
+    /// let grid = (0..20)
+    ///     .map(|i| {
+    ///         (0..15)
+    ///             .map(|j| {
+    ///                 let (x, y) = (i as f64 / 19., j as f64 / 14.);
+    ///                 let noise = ((i * 7 + j * 13) % 5) as f64 * 1e-3;
+    ///                 Point3::new(x, y, x * x + y * y + noise)
+    ///             })
+    ///             .collect()
+    ///     })
+    ///     .collect::<Vec<Vec<_>>>();
+    /// let surface = NurbsSurface3D::try_approximate(&grid, 3, 3, 6, 5, KnotStyle::Chordal).unwrap();
+    /// assert_eq!(surface.control_points().len(), 6);
+    /// assert_eq!(surface.control_points()[0].len(), 5);
+    ///
+    /// // the surface stays close to the points
+    /// for row in grid.iter().step_by(3) {
+    ///     for p in row.iter().step_by(3) {
+    ///         let (u, v) = surface.find_closest_parameter(p).unwrap();
+    ///         assert!((surface.point_at(u, v) - p).norm() < 1e-2);
+    ///     }
+    /// }
+    /// ```
+    pub fn try_approximate(
+        grid: &[Vec<OPoint<T, DimNameDiff<D, U1>>>],
+        u_degree: usize,
+        v_degree: usize,
+        u_count: usize,
+        v_count: usize,
+        knot_style: KnotStyle,
+    ) -> anyhow::Result<Self> {
+        let (nu, nv) = try_grid_size(grid)?;
+        anyhow::ensure!(
+            u_count > u_degree && v_count > v_degree,
+            "The number of control points ({} x {}) must exceed the degrees ({}, {})",
+            u_count,
+            v_count,
+            u_degree,
+            v_degree
+        );
+        anyhow::ensure!(
+            u_count <= nu && v_count <= nv,
+            "The number of control points ({} x {}) must not exceed the number of points ({} x {})",
+            u_count,
+            v_count,
+            nu,
+            nv
+        );
+
+        let (us, vs) = grid_parameters(grid, &knot_style);
+        let points = homogenize_grid(grid);
+
+        // fit in the u direction for each column, then in the v direction for each row
+        let (u_knots, mu) = approximation_matrix(&us, u_degree, u_count);
+        let (v_knots, mv) = approximation_matrix(&vs, v_degree, v_count);
+        let columns = try_fit_columns(&transpose(&points), mu)?;
+        let control_points = try_fit_columns(&transpose(&columns), mv)?;
+
+        Ok(Self::new(
+            u_degree,
+            v_degree,
+            u_knots,
+            v_knots,
+            control_points,
+        ))
+    }
+
+    /// Try to create a surface approximating the scattered points in the least squares sense
+    /// The points are parameterized by the projection onto their best fitting plane,
+    /// so they should form a height field over the plane.
+    /// The surface has `u_count` x `v_count` control points with uniform knot vectors.
+    /// The normal equations are solved as a banded system in O(u_count * v_count * (u_degree * v_count)^2) time,
+    /// so give the larger number of control points to `u_count` for dense control grids.
+    /// # Example
+    /// ```
+    /// use curvo::prelude::*;
+    /// use nalgebra::Point3;
+    ///
+    /// // points scattered over a saddle
+    /// let points = (0..400)
+    ///     .map(|i| {
+    ///         let x = ((i * 37) % 101) as f64 / 100. * 2. - 1.;
+    ///         let y = ((i * 61) % 103) as f64 / 102. * 2. - 1.;
+    ///         Point3::new(x, y, (x * x - y * y) * 0.5)
+    ///     })
+    ///     .collect::<Vec<_>>();
+    /// let surface = NurbsSurface3D::try_approximate_scattered(&points, 3, 3, 5, 5).unwrap();
+    ///
+    /// for p in points.iter().step_by(10) {
+    ///     let (u, v) = surface.find_closest_parameter(p).unwrap();
+    ///     assert!((surface.point_at(u, v) - p).norm() < 1e-3);
+    /// }
+    /// ```
+    pub fn try_approximate_scattered(
+        points: &[OPoint<T, DimNameDiff<D, U1>>],
+        u_degree: usize,
+        v_degree: usize,
+        u_count: usize,
+        v_count: usize,
+    ) -> anyhow::Result<Self> {
+        anyhow::ensure!(
+            u_count > u_degree && v_count > v_degree,
+            "The number of control points ({} x {}) must exceed the degrees ({}, {})",
+            u_count,
+            v_count,
+            u_degree,
+            v_degree
+        );
+        anyhow::ensure!(
+            points.len() >= u_count * v_count,
+            "Too few points ({}) for the number of control points ({} x {})",
+            points.len(),
+            u_count,
+            v_count
+        );
+
+        let coords = points
+            .iter()
+            .map(|p| DVector::from_column_slice(p.coords.as_slice()))
+            .collect_vec();
+        let parameters = try_planar_parameters(&coords)?;
+
+        let u_knots = uniform_knots(u_degree, u_count);
+        let v_knots = uniform_knots(v_degree, v_count);
+        let rows = parameters
+            .iter()
+            .map(|(u, v)| {
+                let su = u_knots.find_knot_span_index(u_count - 1, u_degree, *u);
+                let sv = v_knots.find_knot_span_index(v_count - 1, v_degree, *v);
+                let bu = u_knots.basis_functions(su, *u, u_degree);
+                let bv = v_knots.basis_functions(sv, *v, v_degree);
+                bu.iter()
+                    .enumerate()
+                    .cartesian_product(bv.iter().enumerate())
+                    .map(|((i, nu), (j, nv))| {
+                        (
+                            (su - u_degree + i) * v_count + (sv - v_degree + j),
+                            *nu * *nv,
+                        )
+                    })
+                    .collect_vec()
+            })
+            .collect_vec();
+
+        let dim = D::dim();
+        let rhs = DMatrix::from_fn(points.len(), dim, |i, k| {
+            if k < dim - 1 {
+                coords[i][k]
+            } else {
+                T::one()
+            }
+        });
+        let solved = try_solve_scattered(&rows, (u_count, v_count), (u_degree, v_degree), rhs)?;
+
+        let control_points = (0..u_count)
+            .map(|i| {
+                (0..v_count)
+                    .map(|j| OPoint::from_slice(solved.row(i * v_count + j).transpose().as_slice()))
+                    .collect_vec()
+            })
+            .collect_vec();
+
+        Ok(Self::new(
+            u_degree,
+            v_degree,
+            u_knots,
+            v_knots,
+            control_points,
+        ))
+    }
+}
+
+/// Check the grid is rectangular & get its size
+fn try_grid_size<P>(grid: &[Vec<P>]) -> anyhow::Result<(usize, usize)> {
+    let nu = grid.len();
+    let nv = grid.first().map(|row| row.len()).unwrap_or(0);
+    anyhow::ensure!(
+        nu >= 2 && nv >= 2,
+        "The grid must have at least 2 x 2 points"
+    );
+    anyhow::ensure!(
+        grid.iter().all(|row| row.len() == nv),
+        "All rows of the grid must have the same number of points"
+    );
+    Ok((nu, nv))
+}
+
+/// Convert the grid of points into the homogeneous points with the unit weight
+fn homogenize_grid<T: FloatingPoint, D>(
+    grid: &[Vec<OPoint<T, DimNameDiff<D, U1>>>],
+) -> Vec<Vec<OPoint<T, D>>>
+where
+    D: DimName + DimNameSub<U1>,
+    <D as DimNameSub<U1>>::Output: DimNameAdd<U1>,
+    DefaultAllocator: Allocator<D>,
+    DefaultAllocator: Allocator<DimNameDiff<D, U1>>,
+    DefaultAllocator: Allocator<<<D as DimNameSub<U1>>::Output as DimNameAdd<U1>>::Output>,
+{
+    grid.iter()
+        .map(|row| {
+            row.iter()
+                .map(|p| OPoint::from_slice(p.to_homogeneous().as_slice()))
+                .collect_vec()
+        })
+        .collect_vec()
+}
+
+/// Compute the parameters of the grid in both directions
+/// by averaging the normalized parameters of the rows & columns given by the knot style
+fn grid_parameters<T: FloatingPoint, D>(
+    grid: &[Vec<OPoint<T, D>>],
+    knot_style: &KnotStyle,
+) -> (Vec<T>, Vec<T>)
+where
+    D: DimName,
+    DefaultAllocator: Allocator<D>,
+{
+    let vectors = grid
+        .iter()
+        .map(|row| {
+            row.iter()
+                .map(|p| DVector::from_column_slice(p.coords.as_slice()))
+                .collect_vec()
+        })
+        .collect_vec();
+    let us = averaged_parameters(&transpose(&vectors), knot_style);
+    let vs = averaged_parameters(&vectors, knot_style);
+    (us, vs)
+}
+
+/// Average the normalized parameters of the sequences of points
+/// Degenerated sequences (e.g., collapsed into a pole) are skipped.
+fn averaged_parameters<T: FloatingPoint>(
+    sequences: &[Vec<DVector<T>>],
+    knot_style: &KnotStyle,
+) -> Vec<T> {
+    let n = sequences[0].len();
+    let normalized = sequences
+        .iter()
+        .filter_map(|points| knot_style.normalized_parameters(points))
+        .collect_vec();
+
+    if normalized.is_empty() {
+        let div = T::from_usize(n - 1).unwrap();
+        return (0..n).map(|i| T::from_usize(i).unwrap() / div).collect();
+    }
+
+    let count = T::from_usize(normalized.len()).unwrap();
+    (0..n)
+        .map(|i| normalized.iter().fold(T::zero(), |acc, us| acc + us[i]) / count)
+        .collect()
+}
+
+/// Build the knot vector placed by the parameters & the matrix of basis functions evaluated at the parameters
+/// for the least squares approximation with the given number of control points (The NURBS Book eq. 9.68 & 9.69)
+fn approximation_matrix<T: FloatingPoint>(
+    parameters: &[T],
+    degree: usize,
+    count: usize,
+) -> (KnotVector<T>, DMatrix<T>) {
+    let m = parameters.len();
+    let (first, last) = (parameters[0], parameters[m - 1]);
+    let d = T::from_usize(m).unwrap() / T::from_usize(count - degree).unwrap();
+    let inner = (1..(count - degree)).map(|j| {
+        let jd = T::from_usize(j).unwrap() * d;
+        let i = jd.floor().to_usize().unwrap().clamp(1, m - 1);
+        let alpha = jd - T::from_usize(i).unwrap();
+        parameters[i - 1] * (T::one() - alpha) + parameters[i] * alpha
+    });
+    let knots = KnotVector::new(
+        std::iter::repeat_n(first, degree + 1)
+            .chain(inner)
+            .chain(std::iter::repeat_n(last, degree + 1))
+            .collect(),
+    );
+
+    let a = basis_matrix(&knots, degree, count, parameters);
+    (knots, a)
+}
+
+/// Fit each column of the homogeneous points by the matrix of basis functions in the least squares sense
+fn try_fit_columns<T: FloatingPoint, D: DimName>(
+    columns: &[Vec<OPoint<T, D>>],
+    m: DMatrix<T>,
+) -> anyhow::Result<Vec<Vec<OPoint<T, D>>>>
+where
+    DefaultAllocator: Allocator<D>,
+{
+    let solved = try_solve_least_squares(
+        m,
+        columns
+            .iter()
+            .map(|column| DMatrix::from_fn(column.len(), D::dim(), |i, k| column[i][k]))
+            .collect(),
+    )?;
+    Ok(solved
+        .iter()
+        .map(|solved| {
+            (0..solved.nrows())
+                .map(|i| OPoint::from_slice(solved.row(i).transpose().as_slice()))
+                .collect_vec()
+        })
+        .collect_vec())
+}
+
+/// Solve the least squares problem of the scattered points
+/// whose rows hold the indices & values of the nonzero basis functions,
+/// regularized by the differences between the adjacent control points
+/// The control point (i, j) is indexed as `i * v_count + j`, so the normal matrix is banded
+/// with the half bandwidth `u_degree * v_count + v_degree` (at least `v_count` for the regularization).
+fn try_solve_scattered<T: FloatingPoint>(
+    rows: &[Vec<(usize, T)>],
+    (u_count, v_count): (usize, usize),
+    (u_degree, v_degree): (usize, usize),
+    rhs: DMatrix<T>,
+) -> anyhow::Result<DMatrix<T>> {
+    let n = u_count * v_count;
+    let bandwidth = (u_degree * v_count + v_degree).max(v_count);
+    let mut a = DMatrix::<T>::zeros(n, bandwidth + 1);
+    let mut b = DMatrix::<T>::zeros(n, rhs.ncols());
+    rows.iter().enumerate().for_each(|(r, row)| {
+        row.iter().for_each(|(i, ni)| {
+            row.iter().filter(|(j, _)| j <= i).for_each(|(j, nj)| {
+                a[(*i, *i - *j)] += *ni * *nj;
+            });
+            for k in 0..rhs.ncols() {
+                b[(*i, k)] += *ni * rhs[(r, k)];
+            }
+        });
+    });
+
+    let trace = a.column(0).sum();
+    let weight = trace / T::from_usize(n).unwrap() * T::from_f64(SCATTERED_SMOOTHING).unwrap();
+    let index = |i: usize, j: usize| i * v_count + j;
+    let edges = (0..u_count)
+        .cartesian_product(0..v_count)
+        .flat_map(|(i, j)| {
+            let u = (i + 1 < u_count).then(|| (index(i, j), index(i + 1, j)));
+            let v = (j + 1 < v_count).then(|| (index(i, j), index(i, j + 1)));
+            u.into_iter().chain(v)
+        })
+        .collect_vec();
+    edges.into_iter().for_each(|(i, j)| {
+        a[(i, 0)] += weight;
+        a[(j, 0)] += weight;
+        a[(j, j - i)] -= weight;
+    });
+
+    try_solve_banded_cholesky(a, b)
+}
+
+/// Parameterize the points by the projection onto their best fitting plane
+/// The parameters are normalized into the unit square.
+fn try_planar_parameters<T: FloatingPoint>(points: &[DVector<T>]) -> anyhow::Result<Vec<(T, T)>> {
+    let dim = points[0].len();
+    anyhow::ensure!(dim >= 2, "The points must have at least 2 dimensions");
+
+    let count = T::from_usize(points.len()).unwrap();
+    let centroid = points.iter().fold(DVector::zeros(dim), |acc, p| acc + p) / count;
+    let covariance = points.iter().fold(DMatrix::zeros(dim, dim), |acc, p| {
+        let d = p - &centroid;
+        acc + &d * d.transpose()
+    });
+    let eigen = covariance.symmetric_eigen();
+    let order = (0..dim)
+        .sorted_by(|a, b| {
+            eigen.eigenvalues[*b]
+                .partial_cmp(&eigen.eigenvalues[*a])
+                .unwrap_or(std::cmp::Ordering::Equal)
+        })
+        .collect_vec();
+    anyhow::ensure!(
+        eigen.eigenvalues[order[1]] > T::default_epsilon(),
+        "The points must not be collinear"
+    );
+    let (x, y) = (
+        eigen.eigenvectors.column(order[0]),
+        eigen.eigenvectors.column(order[1]),
+    );
+
+    let projected = points
+        .iter()
+        .map(|p| {
+            let d = p - &centroid;
+            (d.dot(&x), d.dot(&y))
+        })
+        .collect_vec();
+    let (x_min, x_max) = projected
+        .iter()
+        .map(|p| p.0)
+        .minmax()
+        .into_option()
+        .unwrap();
+    let (y_min, y_max) = projected
+        .iter()
+        .map(|p| p.1)
+        .minmax()
+        .into_option()
+        .unwrap();
+    Ok(projected
+        .into_iter()
+        .map(|(x, y)| ((x - x_min) / (x_max - x_min), (y - y_min) / (y_max - y_min)))
+        .collect())
+}
+
+/// Create the clamped uniform knot vector over the unit interval for the number of control points
+fn uniform_knots<T: FloatingPoint>(degree: usize, count: usize) -> KnotVector<T> {
+    let spans = T::from_usize(count - degree).unwrap();
+    KnotVector::new(
+        std::iter::repeat_n(T::zero(), degree + 1)
+            .chain((1..(count - degree)).map(|i| T::from_usize(i).unwrap() / spans))
+            .chain(std::iter::repeat_n(T::one(), degree + 1))
+            .collect(),
+    )
+}
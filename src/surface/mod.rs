@@ -1,6 +1,10 @@
+pub mod fit_nurbs_surface;
+pub mod network_surface;
 pub mod nurbs_surface;
+pub mod sweep_option;
 pub mod trimmed_surface;
 pub use nurbs_surface::*;
+pub use sweep_option::*;
 pub use trimmed_surface::*;
 
 /// The direction in the UV space.
@@ -27,3 +31,6 @@ pub enum FlipDirection {
     V,
     UV,
 }
+
+#[cfg(test)]
+mod tests;
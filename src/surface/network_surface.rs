@@ -0,0 +1,293 @@
+use argmin::core::ArgminFloat;
+use itertools::Itertools;
+use nalgebra::{
+    allocator::Allocator, DefaultAllocator, DimName, DimNameDiff, DimNameSub, OPoint, U1,
+};
+
+use crate::{
+    curve::NurbsCurve,
+    misc::{linear_algebra::try_interpolate_columns, transpose, FloatingPoint, Invertible},
+    surface::{
+        nurbs_surface::{sorted_set_sub, sorted_set_union, try_unify_curve_knot_vectors},
+        NurbsSurface, UVDirection,
+    },
+};
+
+impl<T: FloatingPoint + ArgminFloat, D: DimName> NurbsSurface<T, D>
+where
+    DefaultAllocator: Allocator<D>,
+    D: DimNameSub<U1>,
+    DefaultAllocator: Allocator<DimNameDiff<D, U1>>,
+{
+    /// Try to create a Coons patch bounded by four curves
+    /// The curves are ordered as the result of `try_boundary_curves`:
+    /// the curve at the start of u, the curve at the end of v, the reversed curve at the end of u,
+    /// and the reversed curve at the start of v, forming a closed loop.
+    /// # Example
+    /// ```
+    /// use curvo::prelude::*;
+    /// use nalgebra::{Point3, Vector3};
+    /// use approx::assert_relative_eq;
+    ///
+    /// let p00 = Point3::new(0., 0., 0.);
+    /// let p01 = Point3::new(0., 2., 0.);
+    /// let p11 = Point3::new(2., 2., 0.);
+    /// let p10 = Point3::new(2., 0., 0.);
+    /// let bulge = |a: &Point3<f64>, b: &Point3<f64>, h: f64| {
+    ///     NurbsCurve3D::bezier(&[*a, nalgebra::center(a, b) + Vector3::z() * h, *b])
+    /// };
+    /// let curves = [
+    ///     bulge(&p00, &p01, 1.),
+    ///     bulge(&p01, &p11, -1.),
+    ///     bulge(&p11, &p10, 0.5),
+    ///     NurbsCurve3D::polyline(&[p10, p00], false),
+    /// ];
+    /// let coons = NurbsSurface3D::try_coons(&curves).unwrap();
+    ///
+    /// // the boundary of the patch reproduces the curves
+    /// let boundary = coons.try_boundary_curves().unwrap();
+    /// for (curve, edge) in curves.iter().zip(boundary.iter()) {
+    ///     let (c0, c1) = curve.knots_domain();
+    ///     let (e0, e1) = edge.knots_domain();
+    ///     for i in 0..=8 {
+    ///         let r = i as f64 / 8.;
+    ///         assert_relative_eq!(
+    ///             curve.point_at(c0 + (c1 - c0) * r),
+    ///             edge.point_at(e0 + (e1 - e0) * r),
+    ///             epsilon = 1e-8
+    ///         );
+    ///     }
+    /// }
+    /// ```
+    pub fn try_coons(curves: &[NurbsCurve<T, D>; 4]) -> anyhow::Result<Self> {
+        let eps = T::from_f64(1e-6).unwrap();
+        for (a, b) in curves.iter().circular_tuple_windows() {
+            let (_, end) = a.knots_domain();
+            let (start, _) = b.knots_domain();
+            anyhow::ensure!(
+                (a.point_at(end) - b.point_at(start)).norm() < eps,
+                "The boundary curves must form a closed loop"
+            );
+        }
+
+        let [c0, c1, c2, c3] = curves;
+        Self::try_gordon(&[c3.inverse(), c1.clone()], &[c0.clone(), c2.inverse()])
+    }
+
+    /// Try to create a Gordon surface interpolating the network of curves
+    /// The `u_curves` run along the u direction ordered in the v direction, and the `v_curves` run along the v direction ordered in the u direction.
+    /// Each v curve starts on the first u curve & each u curve starts on the first v curve,
+    /// and the curves of the same direction are expected to cross the other curves at the same relative parameters.
+    /// Rational curves need to have consistent weights at the intersections.
+    /// # Example
+    /// ```
+    /// use curvo::prelude::*;
+    /// use nalgebra::Point3;
+    /// use approx::assert_relative_eq;
+    ///
+    /// // network on the saddle z = x * y
+    /// let f = |x: f64, y: f64| Point3::new(x, y, x * y);
+    /// let ts = [-1., -0.5, 0., 0.5, 1.];
+    /// let u_curves = [-1., 0., 1.]
+    ///     .iter()
+    ///     .map(|y| NurbsCurve3D::try_interpolate(&ts.iter().map(|x| f(*x, *y)).collect::<Vec<_>>(), 3).unwrap())
+    ///     .collect::<Vec<_>>();
+    /// let v_curves = [-1., 0., 1.]
+    ///     .iter()
+    ///     .map(|x| NurbsCurve3D::try_interpolate(&ts.iter().map(|y| f(*x, *y)).collect::<Vec<_>>(), 3).unwrap())
+    ///     .collect::<Vec<_>>();
+    ///
+    /// let gordon = NurbsSurface3D::try_gordon(&u_curves, &v_curves).unwrap();
+    /// let (u, v) = gordon.knots_domain();
+    /// for i in 0..=4 {
+    ///     for j in 0..=4 {
+    ///         let p = gordon.point_at(u.0 + (u.1 - u.0) * i as f64 / 4., v.0 + (v.1 - v.0) * j as f64 / 4.);
+    ///         assert_relative_eq!(p.z, p.x * p.y, epsilon = 1e-8);
+    ///     }
+    /// }
+    /// ```
+    pub fn try_gordon(
+        u_curves: &[NurbsCurve<T, D>],
+        v_curves: &[NurbsCurve<T, D>],
+    ) -> anyhow::Result<Self> {
+        anyhow::ensure!(
+            u_curves.len() >= 2 && v_curves.len() >= 2,
+            "At least two curves are required in each direction"
+        );
+
+        // relative parameters of the intersections on the first curves
+        let us = try_intersection_ratios(
+            &u_curves[0],
+            v_curves.iter().map(|c| c.point_at(c.knots_domain().0)),
+        )?;
+        let vs = try_intersection_ratios(
+            &v_curves[0],
+            u_curves.iter().map(|c| c.point_at(c.knots_domain().0)),
+        )?;
+
+        let u_curves = try_unify_curve_knot_vectors(u_curves)?;
+        let v_curves = try_unify_curve_knot_vectors(v_curves)?;
+        let lerp = |(d0, d1): (T, T), r: &T| d0 + (d1 - d0) * *r;
+        let us = us
+            .iter()
+            .map(|r| lerp(u_curves[0].knots_domain(), r))
+            .collect_vec();
+        let vs = vs
+            .iter()
+            .map(|r| lerp(v_curves[0].knots_domain(), r))
+            .collect_vec();
+
+        // loft the u curves in the v direction
+        let v_degree = v_curves[0].degree().min(vs.len() - 1);
+        let (v_knots, columns) = try_interpolate_columns(
+            &(0..u_curves[0].control_points().len())
+                .map(|k| {
+                    u_curves
+                        .iter()
+                        .map(|c| c.control_points()[k].clone())
+                        .collect_vec()
+                })
+                .collect_vec(),
+            &vs,
+            v_degree,
+        )?;
+        let lu = NurbsSurface::new(
+            u_curves[0].degree(),
+            v_degree,
+            u_curves[0].knots().clone(),
+            v_knots,
+            columns,
+        );
+
+        // loft the v curves in the u direction
+        let u_degree = u_curves[0].degree().min(us.len() - 1);
+        let (u_knots, rows) = try_interpolate_columns(
+            &(0..v_curves[0].control_points().len())
+                .map(|k| {
+                    v_curves
+                        .iter()
+                        .map(|c| c.control_points()[k].clone())
+                        .collect_vec()
+                })
+                .collect_vec(),
+            &us,
+            u_degree,
+        )?;
+        let lv = NurbsSurface::new(
+            u_degree,
+            v_curves[0].degree(),
+            u_knots.clone(),
+            v_curves[0].knots().clone(),
+            transpose(&rows),
+        );
+
+        // tensor product surface interpolating the intersections
+        let (_, rows) = try_interpolate_columns(
+            &u_curves
+                .iter()
+                .map(|c| us.iter().map(|u| c.point(*u)).collect_vec())
+                .collect_vec(),
+            &us,
+            u_degree,
+        )?;
+        let (v_knots, columns) = try_interpolate_columns(&transpose(&rows), &vs, v_degree)?;
+        let tensor = NurbsSurface::new(u_degree, v_degree, u_knots, v_knots, columns);
+
+        let [lu, lv, tensor] = try_make_compatible([lu, lv, tensor])?;
+        let control_points = lu
+            .control_points()
+            .iter()
+            .zip(lv.control_points().iter())
+            .zip(tensor.control_points().iter())
+            .map(|((a, b), c)| {
+                a.iter()
+                    .zip(b.iter())
+                    .zip(c.iter())
+                    .map(|((a, b), c)| OPoint::from(&a.coords + &b.coords - &c.coords))
+                    .collect_vec()
+            })
+            .collect_vec();
+
+        Ok(NurbsSurface::new(
+            lu.u_degree(),
+            lu.v_degree(),
+            lu.u_knots().clone(),
+            lu.v_knots().clone(),
+            control_points,
+        ))
+    }
+}
+
+/// Find the relative parameters of the points on the curve in [0, 1]
+/// The first & the last points are regarded as the ends of the curve.
+fn try_intersection_ratios<T: FloatingPoint + ArgminFloat, D>(
+    curve: &NurbsCurve<T, D>,
+    points: impl ExactSizeIterator<Item = OPoint<T, DimNameDiff<D, U1>>>,
+) -> anyhow::Result<Vec<T>>
+where
+    DefaultAllocator: Allocator<D>,
+    D: DimName + DimNameSub<U1>,
+    DefaultAllocator: Allocator<DimNameDiff<D, U1>>,
+{
+    let n = points.len();
+    let (d0, d1) = curve.knots_domain();
+    let ratios = points
+        .enumerate()
+        .map(|(i, p)| {
+            if i == 0 {
+                Ok(T::zero())
+            } else if i == n - 1 {
+                Ok(T::one())
+            } else {
+                let t = curve.find_closest_parameter(&p)?;
+                Ok((t - d0) / (d1 - d0))
+            }
+        })
+        .collect::<anyhow::Result<Vec<_>>>()?;
+    anyhow::ensure!(
+        ratios.windows(2).all(|w| w[0] < w[1]),
+        "The curves must be ordered along the crossing curves"
+    );
+    Ok(ratios)
+}
+
+/// Elevate the degrees & merge the knot vectors of the surfaces in both directions
+/// so that the surfaces share the same degrees, knot vectors & number of control points
+fn try_make_compatible<T: FloatingPoint, D, const N: usize>(
+    surfaces: [NurbsSurface<T, D>; N],
+) -> anyhow::Result<[NurbsSurface<T, D>; N]>
+where
+    DefaultAllocator: Allocator<D>,
+    D: DimName + DimNameSub<U1>,
+    DefaultAllocator: Allocator<DimNameDiff<D, U1>>,
+{
+    let mut surfaces = surfaces;
+    for direction in [UVDirection::U, UVDirection::V] {
+        let degree = surfaces
+            .iter()
+            .map(|s| match direction {
+                UVDirection::U => s.u_degree(),
+                UVDirection::V => s.v_degree(),
+            })
+            .max()
+            .unwrap_or(1);
+        for s in surfaces.iter_mut() {
+            *s = s.try_elevate_degree(direction, degree)?;
+        }
+
+        let knots = |s: &NurbsSurface<T, D>| match direction {
+            UVDirection::U => s.u_knots().to_vec(),
+            UVDirection::V => s.v_knots().to_vec(),
+        };
+        let merged = surfaces
+            .iter()
+            .fold(vec![], |acc, s| sorted_set_union(&knots(s), &acc));
+        for s in surfaces.iter_mut() {
+            let rem = sorted_set_sub(&merged, &knots(s));
+            if !rem.is_empty() {
+                s.try_refine_knot(rem, direction)?;
+            }
+        }
+    }
+    Ok(surfaces)
+}
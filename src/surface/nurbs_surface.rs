@@ -3,9 +3,9 @@ use std::borrow::Cow;
 use argmin::core::{ArgminFloat, Executor, State};
 use itertools::Itertools;
 use nalgebra::{
-    allocator::Allocator, Const, DVector, DefaultAllocator, DimName, DimNameAdd, DimNameDiff,
-    DimNameSub, DimNameSum, OMatrix, OPoint, OVector, Point3, Point4, RealField, Vector2, Vector3,
-    U1,
+    allocator::Allocator, Const, DMatrix, DVector, DefaultAllocator, DimName, DimNameAdd,
+    DimNameDiff, DimNameSub, DimNameSum, Matrix3, Matrix4, OMatrix, OPoint, OVector, Point3,
+    Point4, RealField, Rotation3, Vector2, Vector3, U1,
 };
 use simba::scalar::SupersetOf;
 
@@ -15,14 +15,23 @@ use crate::{
         try_interpolate_control_points,
     },
     misc::{
-        binomial::Binomial, transformable::Transformable, transpose_control_points, FloatingPoint,
-        Invertible, Ray,
+        binomial::Binomial,
+        frenet_frame::FrenetFrame,
+        linear_algebra::{basis_matrix, try_solve_least_squares},
+        transformable::Transformable,
+        transpose_control_points, FloatingPoint, Invertible, Ray,
     },
     prelude::{AdaptiveTessellationOptions, KnotVector, SurfaceTessellation, Tessellation},
     SurfaceClosestParameterNewton, SurfaceClosestParameterProblem,
 };
 
-use super::{FlipDirection, UVDirection};
+use super::{FlipDirection, Sweep2Option, SweepFrame, SweepOption, UVDirection};
+
+/// Number of the samples per degree in each span to fit the surface of the reduced degree
+const DEGREE_REDUCTION_SAMPLES: usize = 2;
+
+/// Maximum number of the knot insertions to reduce the degree within the tolerance
+const MAX_DEGREE_REDUCTION_REFINEMENTS: usize = 8;
 
 /// NURBS surface representation
 /// by generics, it can be used for 2D or 3D curves with f32 or f64 scalar types
@@ -897,6 +906,436 @@ where
         Ok(())
     }
 
+    /// Try to insert the knot into the surface in the direction the given number of times
+    /// The shape of the surface is kept.
+    /// # Example
+    /// ```
+    /// use curvo::prelude::*;
+    /// use nalgebra::{Point3, Vector3};
+    /// use approx::assert_relative_eq;
+    ///
+    /// let circle = NurbsCurve3D::try_circle(&Point3::origin(), &Vector3::x(), &Vector3::y(), 1.).unwrap();
+    /// let cylinder = NurbsSurface3D::extrude(&circle, &Vector3::z());
+    /// let mut refined = cylinder.clone();
+    /// refined.try_add_knot(UVDirection::U, 0.25, 2).unwrap();
+    /// assert_eq!(refined.u_knots().len(), cylinder.u_knots().len() + 2);
+    /// assert_eq!(refined.control_points().len(), cylinder.control_points().len() + 2);
+    ///
+    /// let ((u0, u1), (v0, v1)) = cylinder.knots_domain();
+    /// for i in 0..=8 {
+    ///     for j in 0..=8 {
+    ///         let u = u0 + (u1 - u0) * i as f64 / 8.;
+    ///         let v = v0 + (v1 - v0) * j as f64 / 8.;
+    ///         assert_relative_eq!(cylinder.point_at(u, v), refined.point_at(u, v), epsilon = 1e-8);
+    ///     }
+    /// }
+    /// ```
+    pub fn try_add_knot(
+        &mut self,
+        direction: UVDirection,
+        knot: T,
+        multiplicity: usize,
+    ) -> anyhow::Result<()> {
+        let (start, end) = self.knots_domain_at(direction);
+        anyhow::ensure!(
+            start < knot && knot < end,
+            "The knot {} is out of the domain ({}, {})",
+            knot,
+            start,
+            end
+        );
+        let (degree, knots) = match direction {
+            UVDirection::U => (self.u_degree, &self.u_knots),
+            UVDirection::V => (self.v_degree, &self.v_knots),
+        };
+        let current = knots.iter().filter(|k| **k == knot).count();
+        anyhow::ensure!(
+            current + multiplicity <= degree,
+            "The multiplicity of the knot {} exceeds the degree {}",
+            current + multiplicity,
+            degree
+        );
+        self.try_refine_knot(vec![knot; multiplicity], direction)
+    }
+
+    /// Try to remove the knot from the surface in the direction as many times as possible within the tolerance
+    /// The knot is removed from every row of the control points in the direction at once,
+    /// so it is removed only as many times as all the rows allow.
+    /// Returns the number of the knots removed
+    /// # Example
+    /// ```
+    /// use curvo::prelude::*;
+    /// use nalgebra::{Point3, Vector3};
+    ///
+    /// let circle = NurbsCurve3D::try_circle(&Point3::origin(), &Vector3::x(), &Vector3::y(), 1.).unwrap();
+    /// let cylinder = NurbsSurface3D::extrude(&circle, &Vector3::z());
+    /// let mut refined = cylinder.clone();
+    /// refined.try_add_knot(UVDirection::U, 0.25, 2).unwrap();
+    ///
+    /// let removed = refined.try_remove_knot(UVDirection::U, 0.25, Some(1e-8)).unwrap();
+    /// assert_eq!(removed, 2);
+    /// assert_eq!(refined.u_knots(), cylinder.u_knots());
+    ///
+    /// // the knots of the circle can't be removed without changing the shape
+    /// let mut surface = cylinder.clone();
+    /// let knot = surface.v_knots()[3];
+    /// let removed = surface.try_remove_knot(UVDirection::V, knot, Some(1e-8)).unwrap();
+    /// assert_eq!(removed, 0);
+    /// assert_eq!(surface, cylinder);
+    /// ```
+    pub fn try_remove_knot(
+        &mut self,
+        direction: UVDirection,
+        knot: T,
+        tolerance: Option<T>,
+    ) -> anyhow::Result<usize> {
+        let (degree, knots, rows) = self.rows_at(direction);
+        let curves = rows
+            .into_iter()
+            .map(|row| NurbsCurve::try_new(degree, row, knots.to_vec()))
+            .collect::<anyhow::Result<Vec<_>>>()?;
+
+        let times = curves
+            .iter()
+            .map(|curve| {
+                curve
+                    .clone()
+                    .try_remove_knot_times(knot, usize::MAX, tolerance)
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?
+            .into_iter()
+            .min()
+            .unwrap_or(0);
+        if times == 0 {
+            return Ok(0);
+        }
+
+        let removed = curves
+            .into_iter()
+            .map(|mut curve| {
+                curve.try_remove_knot_times(knot, times, tolerance)?;
+                Ok(curve)
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+        let knots = removed
+            .first()
+            .map(|c| c.knots().clone())
+            .ok_or(anyhow::anyhow!("No curves"))?;
+        let rows = removed
+            .into_iter()
+            .map(|c| c.control_points().clone())
+            .collect_vec();
+        *self = self.with_rows_at(direction, degree, knots, rows);
+
+        Ok(times)
+    }
+
+    /// Try to remove as many knots as possible from the surface in the direction within the tolerance
+    /// Returns the number of the knots removed
+    /// # Example
+    /// ```
+    /// use curvo::prelude::*;
+    /// use nalgebra::{Point3, Vector3};
+    /// use approx::assert_relative_eq;
+    ///
+    /// let profile = NurbsCurve3D::try_interpolate(
+    ///     &[
+    ///         Point3::new(0., 0., 0.),
+    ///         Point3::new(1., 0., 1.),
+    ///         Point3::new(2., 0., -1.),
+    ///         Point3::new(3., 0., 0.),
+    ///     ],
+    ///     3,
+    /// )
+    /// .unwrap();
+    /// let surface = NurbsSurface3D::extrude(&profile, &Vector3::y());
+    ///
+    /// // bloat the knots
+    /// let mut bloated = surface.clone();
+    /// bloated.try_refine_knot(vec![0.1, 0.2, 0.3, 0.6, 0.7, 0.9], UVDirection::V).unwrap();
+    ///
+    /// let removed = bloated.try_reduce_knots(UVDirection::V, Some(1e-8)).unwrap();
+    /// assert_eq!(removed, 6);
+    /// assert_eq!(bloated.v_knots().len(), surface.v_knots().len());
+    ///
+    /// let ((u0, u1), (v0, v1)) = surface.knots_domain();
+    /// for i in 0..=8 {
+    ///     for j in 0..=8 {
+    ///         let u = u0 + (u1 - u0) * i as f64 / 8.;
+    ///         let v = v0 + (v1 - v0) * j as f64 / 8.;
+    ///         assert_relative_eq!(surface.point_at(u, v), bloated.point_at(u, v), epsilon = 1e-6);
+    ///     }
+    /// }
+    /// ```
+    pub fn try_reduce_knots(
+        &mut self,
+        direction: UVDirection,
+        tolerance: Option<T>,
+    ) -> anyhow::Result<usize> {
+        let mut total_removed = 0;
+        let mut changed = true;
+
+        // continue until no more knots can be removed
+        while changed {
+            changed = false;
+            let knots = match direction {
+                UVDirection::U => &self.u_knots,
+                UVDirection::V => &self.v_knots,
+            };
+            let (first, last) = (knots.first(), knots.last());
+
+            // try to remove the inner knots starting from those with highest multiplicity
+            let candidates = knots
+                .multiplicity()
+                .into_iter()
+                .filter(|m| *m.knot() != first && *m.knot() != last && m.multiplicity() > 0)
+                .sorted_by_key(|m| std::cmp::Reverse(m.multiplicity()))
+                .map(|m| *m.knot())
+                .collect_vec();
+
+            for knot in candidates {
+                let removed = self.try_remove_knot(direction, knot, tolerance)?;
+                if removed > 0 {
+                    total_removed += removed;
+                    changed = true;
+                }
+            }
+        }
+
+        Ok(total_removed)
+    }
+
+    /// Try to elevate the degree of the surface in the direction
+    /// # Example
+    /// ```
+    /// use curvo::prelude::*;
+    /// use nalgebra::{Point3, Vector3};
+    /// use approx::assert_relative_eq;
+    ///
+    /// let circle = NurbsCurve3D::try_circle(&Point3::origin(), &Vector3::x(), &Vector3::y(), 1.).unwrap();
+    /// let cylinder = NurbsSurface3D::extrude(&circle, &Vector3::z());
+    /// let elevated = cylinder.try_elevate_degree(UVDirection::V, 3).unwrap();
+    /// assert_eq!(elevated.u_degree(), cylinder.u_degree());
+    /// assert_eq!(elevated.v_degree(), 3);
+    ///
+    /// let ((u0, u1), (v0, v1)) = cylinder.knots_domain();
+    /// for i in 0..=8 {
+    ///     for j in 0..=8 {
+    ///         let u = u0 + (u1 - u0) * i as f64 / 8.;
+    ///         let v = v0 + (v1 - v0) * j as f64 / 8.;
+    ///         assert_relative_eq!(cylinder.point_at(u, v), elevated.point_at(u, v), epsilon = 1e-8);
+    ///     }
+    /// }
+    /// ```
+    pub fn try_elevate_degree(
+        &self,
+        direction: UVDirection,
+        target_degree: usize,
+    ) -> anyhow::Result<Self> {
+        let (degree, knots, rows) = self.rows_at(direction);
+        if target_degree <= degree {
+            return Ok(self.clone());
+        }
+
+        let elevated = rows
+            .into_iter()
+            .map(|row| {
+                NurbsCurve::try_new(degree, row, knots.to_vec())?.try_elevate_degree(target_degree)
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+        let knots = elevated
+            .first()
+            .map(|c| c.knots().clone())
+            .ok_or(anyhow::anyhow!("No control points"))?;
+        let rows = elevated
+            .into_iter()
+            .map(|c| c.control_points().clone())
+            .collect_vec();
+
+        Ok(self.with_rows_at(direction, target_degree, knots, rows))
+    }
+
+    /// Try to reduce the degree of the surface in the direction within the tolerance
+    /// Each row of the control points in the direction is fitted by the target degree in the least squares sense with its ends fixed,
+    /// keeping the knots & the continuity at them as far as the target degree allows.
+    /// Knots are inserted into the spans deviating more than the tolerance until the surface fits within the tolerance.
+    /// # Example
+    /// ```
+    /// use curvo::prelude::*;
+    /// use nalgebra::{Point3, Vector3};
+    /// use approx::assert_relative_eq;
+    ///
+    /// let profile = NurbsCurve3D::bezier(&[
+    ///     Point3::new(0., 0., 0.),
+    ///     Point3::new(1., 0., 1.),
+    ///     Point3::new(2., 0., -1.),
+    ///     Point3::new(3., 0., 0.5),
+    ///     Point3::new(4., 0., 0.),
+    ///     Point3::new(5., 0., 0.2),
+    /// ]);
+    /// let surface = NurbsSurface3D::extrude(&profile, &Vector3::y());
+    /// assert_eq!(surface.v_degree(), 5);
+    ///
+    /// let tolerance = 1e-3;
+    /// let reduced = surface.try_reduce_degree(UVDirection::V, 3, tolerance).unwrap();
+    /// assert_eq!(reduced.v_degree(), 3);
+    ///
+    /// let ((u0, u1), (v0, v1)) = surface.knots_domain();
+    /// assert_relative_eq!(reduced.point_at(u0, v0), surface.point_at(u0, v0), epsilon = 1e-8);
+    /// assert_relative_eq!(reduced.point_at(u1, v1), surface.point_at(u1, v1), epsilon = 1e-8);
+    /// for i in 0..=32 {
+    ///     let u = (u0 + u1) / 2.;
+    ///     let v = v0 + (v1 - v0) * i as f64 / 32.;
+    ///     assert!((reduced.point_at(u, v) - surface.point_at(u, v)).norm() < tolerance);
+    /// }
+    /// ```
+    pub fn try_reduce_degree(
+        &self,
+        direction: UVDirection,
+        target_degree: usize,
+        tolerance: T,
+    ) -> anyhow::Result<Self> {
+        anyhow::ensure!(target_degree >= 1, "The target degree must be at least 1");
+        anyhow::ensure!(
+            tolerance > T::zero(),
+            "Tolerance must be positive, but got {}",
+            tolerance
+        );
+        let (degree, knots, rows) = self.rows_at(direction);
+        if target_degree >= degree {
+            return Ok(self.clone());
+        }
+
+        let (start, end) = self.knots_domain_at(direction);
+        let reduction = degree - target_degree;
+        let mut inner = knots
+            .multiplicity()
+            .into_iter()
+            .filter(|m| start < *m.knot() && *m.knot() < end)
+            .flat_map(|m| {
+                std::iter::repeat_n(*m.knot(), m.multiplicity().saturating_sub(reduction).max(1))
+            })
+            .collect_vec();
+
+        let count = rows.first().map(|row| row.len()).unwrap_or(0);
+        let original = rows
+            .iter()
+            .map(|row| DMatrix::from_fn(row.len(), D::dim(), |i, k| row[i][k]))
+            .collect_vec();
+
+        // parameters across the direction to measure the deviation
+        let (other_start, other_end) = self.knots_domain_at(direction.opposite());
+        let others = (0..=(rows.len() * DEGREE_REDUCTION_SAMPLES))
+            .map(|i| {
+                other_start
+                    + (other_end - other_start) * T::from_usize(i).unwrap()
+                        / T::from_usize(rows.len() * DEGREE_REDUCTION_SAMPLES).unwrap()
+            })
+            .collect_vec();
+        let point_at = |surface: &Self, s: T, t: T| match direction {
+            UVDirection::U => surface.point_at(s, t),
+            UVDirection::V => surface.point_at(t, s),
+        };
+
+        for _ in 0..MAX_DEGREE_REDUCTION_REFINEMENTS {
+            let reduced_knots = KnotVector::new(
+                std::iter::repeat_n(start, target_degree + 1)
+                    .chain(inner.iter().copied())
+                    .chain(std::iter::repeat_n(end, target_degree + 1))
+                    .collect(),
+            );
+            let spans = std::iter::once(start)
+                .chain(inner.iter().copied())
+                .chain(std::iter::once(end))
+                .dedup()
+                .tuple_windows()
+                .collect_vec();
+            let samples = spans
+                .iter()
+                .flat_map(|(a, b)| {
+                    let n = DEGREE_REDUCTION_SAMPLES * (target_degree + 1);
+                    let div = T::from_usize(n).unwrap();
+                    (0..n).map(move |i| *a + (*b - *a) * T::from_usize(i).unwrap() / div)
+                })
+                .chain(std::iter::once(end))
+                .collect_vec();
+
+            let solved = try_fit_rows_with_fixed_ends(
+                basis_matrix(knots, degree, count, &samples),
+                basis_matrix(
+                    &reduced_knots,
+                    target_degree,
+                    reduced_knots.len() - target_degree - 1,
+                    &samples,
+                ),
+                &original,
+            )?;
+            let reduced_rows = solved
+                .iter()
+                .map(|m| {
+                    (0..m.nrows())
+                        .map(|i| OPoint::from_slice(m.row(i).transpose().as_slice()))
+                        .collect_vec()
+                })
+                .collect_vec();
+            let reduced = self.with_rows_at(direction, target_degree, reduced_knots, reduced_rows);
+
+            let exceeded = spans
+                .iter()
+                .filter(|(a, b)| {
+                    samples.iter().filter(|s| *a <= **s && **s <= *b).any(|s| {
+                        others.iter().any(|t| {
+                            (point_at(self, *s, *t) - point_at(&reduced, *s, *t)).norm() > tolerance
+                        })
+                    })
+                })
+                .map(|(a, b)| (*a + *b) / T::from_f64(2.).unwrap())
+                .collect_vec();
+            if exceeded.is_empty() {
+                return Ok(reduced);
+            }
+            inner = sorted_set_union(&inner, &exceeded);
+        }
+
+        anyhow::bail!(
+            "Failed to reduce the degree within the tolerance {}",
+            tolerance
+        )
+    }
+
+    /// Get the degree, the knot vector & the rows of the control points along the direction
+    #[allow(clippy::type_complexity)]
+    fn rows_at(&self, direction: UVDirection) -> (usize, &KnotVector<T>, Vec<Vec<OPoint<T, D>>>) {
+        match direction {
+            UVDirection::U => (
+                self.u_degree,
+                &self.u_knots,
+                self.transposed_control_points(),
+            ),
+            UVDirection::V => (self.v_degree, &self.v_knots, self.control_points.clone()),
+        }
+    }
+
+    /// Create the surface replacing the degree, the knot vector & the rows of the control points along the direction
+    fn with_rows_at(
+        &self,
+        direction: UVDirection,
+        degree: usize,
+        knots: KnotVector<T>,
+        rows: Vec<Vec<OPoint<T, D>>>,
+    ) -> Self {
+        match direction {
+            UVDirection::U => Self::new(
+                degree,
+                self.v_degree,
+                knots,
+                self.v_knots.clone(),
+                transpose_control_points(&rows),
+            ),
+            UVDirection::V => Self::new(self.u_degree, degree, self.u_knots.clone(), knots, rows),
+        }
+    }
+
     /// Find the closest point on the surface to a given point
     ///
     /// # Example
@@ -1123,24 +1562,211 @@ impl<T: FloatingPoint> NurbsSurface3D<T> {
         rail: &NurbsCurve3D<T>,
         degree_v: Option<usize>,
     ) -> anyhow::Result<Self> {
-        let (start, end) = rail.knots_domain();
-        let samples = rail.control_points().len() * 2;
-        let span = (end - start) / T::from_usize(samples - 1).unwrap();
+        Self::try_sweep_with_option(
+            profile,
+            rail,
+            SweepOption::default().with_degree_v(degree_v),
+        )
+    }
 
-        let parameters: Vec<_> = (0..samples)
-            .map(|i| start + T::from_usize(i).unwrap() * span)
-            .collect();
+    /// Try to sweep a profile curve along a rail curve with the option
+    /// The profile defined on the local xy plane is placed on the frames along the rail,
+    /// where the local z axis is mapped to the tangent & the local y axis to the normal of the frame.
+    /// The profile is scaled & twisted around the tangent by the laws of the option.
+    /// # Example
+    /// ```
+    /// use curvo::prelude::*;
+    /// use nalgebra::{Point3, Vector3};
+    /// use approx::assert_relative_eq;
+    ///
+    /// // S-shaped rail on the xy plane with an inflection
+    /// let rail = NurbsCurve3D::<f64>::try_interpolate(&[
+    ///     Point3::new(0., 0., 0.),
+    ///     Point3::new(1., 1., 0.),
+    ///     Point3::new(2., 0., 0.),
+    ///     Point3::new(3., -1., 0.),
+    ///     Point3::new(4., 0., 0.),
+    /// ], 3).unwrap();
+    /// let profile = NurbsCurve3D::polyline(&[Point3::new(0., -0.5, 0.), Point3::new(0., 0.5, 0.)], false);
+    ///
+    /// let option = SweepOption::default()
+    ///     .with_frame(SweepFrame::Fixed(Vector3::z()))
+    ///     .with_scale(SweepLaw::keys(vec![(0., 1.), (1., 2.)]));
+    /// let swept = NurbsSurface3D::try_sweep_with_option(&profile, &rail, option).unwrap();
+    ///
+    /// // the ribbon stands upright on the rail without flipping
+    /// let (u, v) = swept.knots_domain();
+    /// let start = swept.point_at(u.0, v.0);
+    /// let end = swept.point_at(u.0, v.1);
+    /// assert_relative_eq!(start.z.abs(), 0.5, epsilon = 1e-8);
+    /// assert_relative_eq!(end.z, 2. * start.z, epsilon = 1e-8);
+    /// for i in 0..=16 {
+    ///     let p = swept.point_at(u.0, v.0 + (v.1 - v.0) * i as f64 / 16.);
+    ///     assert!(p.z * start.z > 0.);
+    /// }
+    /// ```
+    pub fn try_sweep_with_option(
+        profile: &NurbsCurve3D<T>,
+        rail: &NurbsCurve3D<T>,
+        option: SweepOption<T>,
+    ) -> anyhow::Result<Self> {
+        let (ratios, parameters) = sweep_parameters(rail, option.samples())?;
 
-        let frames = rail.compute_frenet_frames(&parameters);
-        let curves: Vec<_> = frames
+        let frames = match option.frame() {
+            SweepFrame::Frenet => rail.compute_frenet_frames(&parameters),
+            SweepFrame::RotationMinimizing => {
+                rail.compute_rotation_minimizing_frames(&parameters, None)
+            }
+            SweepFrame::Fixed(up) => parameters
+                .iter()
+                .map(|u| {
+                    let (position, tangent) = rail.point_tangent_at(*u);
+                    let tangent = tangent.normalize();
+                    let normal = up - tangent * tangent.dot(up);
+                    anyhow::ensure!(
+                        normal.norm() > T::default_epsilon(),
+                        "The up vector must not be parallel to the rail"
+                    );
+                    let normal = normal.normalize();
+                    Ok(FrenetFrame::new(
+                        position,
+                        tangent,
+                        normal,
+                        tangent.cross(&normal),
+                    ))
+                })
+                .collect::<anyhow::Result<Vec<_>>>()?,
+        };
+
+        let curves = frames
             .iter()
-            .map(|frame| {
-                let transform = frame.matrix();
-                profile.transformed(&transform.into())
+            .zip(ratios.iter())
+            .map(|(frame, r)| {
+                let twist = Rotation3::from_axis_angle(&Vector3::z_axis(), option.twist().at(*r));
+                let transform = frame.matrix().to_homogeneous()
+                    * twist.to_homogeneous()
+                    * Matrix4::new_scaling(option.scale().at(*r));
+                profile.transformed(&transform)
             })
-            .collect();
+            .collect_vec();
 
-        Self::try_loft(&curves, degree_v)
+        Self::try_loft(&curves, option.degree_v())
+    }
+
+    /// Try to sweep a profile curve along two rail curves to create a surface
+    /// The profile must connect the start of the first rail to the start of the second rail within the tolerance of the option.
+    /// It is moved, rotated & scaled uniformly so that its ends follow the rails,
+    /// where the points on the rails are matched by the normalized arc length.
+    /// # Example
+    /// ```
+    /// use curvo::prelude::*;
+    /// use nalgebra::Point3;
+    /// use approx::assert_relative_eq;
+    ///
+    /// let rail0 = NurbsCurve3D::polyline(&[Point3::new(0., 0., 0.), Point3::new(0., 4., 0.)], false);
+    /// let rail1 = NurbsCurve3D::try_interpolate(&[
+    ///     Point3::new(2., 0., 0.),
+    ///     Point3::new(2.5, 1., 0.),
+    ///     Point3::new(3.5, 3., 0.),
+    ///     Point3::new(4., 4., 0.),
+    /// ], 3).unwrap();
+    /// let profile = NurbsCurve3D::bezier(&[
+    ///     Point3::new(0., 0., 0.),
+    ///     Point3::new(1., 0., 1.),
+    ///     Point3::new(2., 0., 0.),
+    /// ]);
+    ///
+    /// let swept = NurbsSurface3D::try_sweep2(&profile, &rail0, &rail1, Sweep2Option::default()).unwrap();
+    /// let (u, v) = swept.knots_domain();
+    /// assert_relative_eq!(swept.point_at(u.0, v.1), Point3::new(0., 4., 0.), epsilon = 1e-8);
+    /// assert_relative_eq!(swept.point_at(u.1, v.1), Point3::new(4., 4., 0.), epsilon = 1e-8);
+    ///
+    /// // the profile doubles its size at the end as the rails get apart
+    /// let (p0, p1) = (swept.point_at((u.0 + u.1) / 2., v.0), swept.point_at((u.0 + u.1) / 2., v.1));
+    /// assert_relative_eq!(p0.z, 0.5, epsilon = 1e-8);
+    /// assert_relative_eq!(p1.z, 1., epsilon = 1e-8);
+    ///
+    /// // the profile must start on the first rail & end on the second rail
+    /// let shifted = NurbsCurve3D::bezier(&[
+    ///     Point3::new(0., 1., 0.),
+    ///     Point3::new(1., 1., 1.),
+    ///     Point3::new(2., 1., 0.),
+    /// ]);
+    /// assert!(NurbsSurface3D::try_sweep2(&shifted, &rail0, &rail1, Sweep2Option::default()).is_err());
+    /// ```
+    pub fn try_sweep2(
+        profile: &NurbsCurve3D<T>,
+        rail0: &NurbsCurve3D<T>,
+        rail1: &NurbsCurve3D<T>,
+        option: Sweep2Option<T>,
+    ) -> anyhow::Result<Self> {
+        let (start, end) = profile.knots_domain();
+        let (p0, p1) = (profile.point_at(start), profile.point_at(end));
+        let (r0, r1) = (
+            rail0.point_at(rail0.knots_domain().0),
+            rail1.point_at(rail1.knots_domain().0),
+        );
+        anyhow::ensure!(
+            (p0 - r0).norm() <= option.tolerance() && (p1 - r1).norm() <= option.tolerance(),
+            "The profile must connect the start of the first rail to the start of the second rail"
+        );
+
+        let samples = option.samples().unwrap_or(
+            rail0
+                .control_points()
+                .len()
+                .max(rail1.control_points().len())
+                * 2,
+        );
+        let (ratios, _) = sweep_parameters(rail0, Some(samples))?;
+
+        // match the rails by the normalized arc length to be independent of their parameterizations
+        let parameters0 = arc_length_parameters(rail0, &ratios)?;
+        let parameters1 = arc_length_parameters(rail1, &ratios)?;
+
+        // frames spanned by the chord between the rails & the mean tangent of the rails
+        let sections = parameters0
+            .iter()
+            .zip(parameters1.iter())
+            .map(|(t0, t1)| {
+                let at = |rail: &NurbsCurve3D<T>, t: T| {
+                    let (p, t) = rail.point_tangent_at(t);
+                    (p, t.normalize())
+                };
+                let ((a, ta), (b, tb)) = (at(rail0, *t0), at(rail1, *t1));
+                let chord = b - a;
+                let length = chord.norm();
+                anyhow::ensure!(
+                    length > T::default_epsilon(),
+                    "The rails must not touch each other"
+                );
+                let x = chord / length;
+                let tangent = ta + tb;
+                let z = tangent - x * x.dot(&tangent);
+                anyhow::ensure!(
+                    z.norm() > T::default_epsilon(),
+                    "The rails must not run along the chord between them"
+                );
+                let z = z.normalize();
+                Ok((a, length, Matrix3::from_columns(&[x, z.cross(&x), z])))
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+
+        let (a0, l0, r0) = &sections[0];
+        let curves = sections
+            .iter()
+            .map(|(a, l, r)| {
+                // p' = a + s * R * R0^T * (p - a0)
+                let linear = r * r0.transpose() * (*l / *l0);
+                let mut transform = linear.to_homogeneous();
+                transform
+                    .fixed_view_mut::<3, 1>(0, 3)
+                    .copy_from(&(a.coords - linear * a0.coords));
+                profile.transformed(&transform)
+            })
+            .collect_vec();
+
+        Self::try_loft(&curves, option.degree_v())
     }
 
     /// Try to revolve a profile curve around an axis to create a surface
@@ -1327,9 +1953,45 @@ impl<T: FloatingPoint> NurbsSurface3D<T> {
     }
 }
 
+/// Sample the normalized positions & the parameters along the rail
+fn sweep_parameters<T: FloatingPoint>(
+    rail: &NurbsCurve3D<T>,
+    samples: Option<usize>,
+) -> anyhow::Result<(Vec<T>, Vec<T>)> {
+    let samples = samples.unwrap_or(rail.control_points().len() * 2);
+    anyhow::ensure!(samples >= 2, "At least two samples are required");
+    let (start, end) = rail.knots_domain();
+    let ratios = (0..samples)
+        .map(|i| T::from_usize(i).unwrap() / T::from_usize(samples - 1).unwrap())
+        .collect_vec();
+    let parameters = ratios.iter().map(|r| start + (end - start) * *r).collect();
+    Ok((ratios, parameters))
+}
+
+/// Find the parameters on the rail at the ratios of the arc length to the whole length of the rail
+fn arc_length_parameters<T: FloatingPoint>(
+    rail: &NurbsCurve3D<T>,
+    ratios: &[T],
+) -> anyhow::Result<Vec<T>> {
+    let (start, end) = rail.knots_domain();
+    let length = rail.try_length()?;
+    ratios
+        .iter()
+        .map(|r| {
+            if *r <= T::zero() {
+                Ok(start)
+            } else if *r >= T::one() {
+                Ok(end)
+            } else {
+                rail.try_parameter_at_length(length * *r, None)
+            }
+        })
+        .collect()
+}
+
 /// Unify the knot vectors of a collection of NURBS curves
 ///
-fn try_unify_curve_knot_vectors<T, D>(
+pub(crate) fn try_unify_curve_knot_vectors<T, D>(
     curves: &[NurbsCurve<T, D>],
 ) -> anyhow::Result<Vec<NurbsCurve<T, D>>>
 where
@@ -1396,7 +2058,53 @@ where
     Ok(curves)
 }
 
-fn sorted_set_union<T: RealField + Copy>(a: &[T], b: &[T]) -> Vec<T> {
+/// Fit the rows of the homogeneous control points evaluated by the original basis matrix
+/// by the reduced basis matrix in the least squares sense with the end points fixed
+fn try_fit_rows_with_fixed_ends<T: FloatingPoint>(
+    original: DMatrix<T>,
+    reduced: DMatrix<T>,
+    rows: &[DMatrix<T>],
+) -> anyhow::Result<Vec<DMatrix<T>>> {
+    let (n, count) = reduced.shape();
+    let values = rows.iter().map(|row| &original * row).collect_vec();
+
+    let inner = reduced.columns(1, count - 2).into_owned();
+    let rhs = values
+        .iter()
+        .map(|v| {
+            let (first, last) = (v.row(0), v.row(n - 1));
+            let mut rhs = v.clone();
+            for i in 0..n {
+                let row = first * reduced[(i, 0)] + last * reduced[(i, count - 1)];
+                rhs.set_row(i, &(rhs.row(i) - row));
+            }
+            rhs
+        })
+        .collect_vec();
+    let solved = if count > 2 {
+        try_solve_least_squares(inner, rhs)?
+    } else {
+        vec![DMatrix::zeros(0, 0); values.len()]
+    };
+
+    Ok(values
+        .iter()
+        .zip(solved)
+        .map(|(v, solved)| {
+            DMatrix::from_fn(count, v.ncols(), |i, k| {
+                if i == 0 {
+                    v[(0, k)]
+                } else if i == count - 1 {
+                    v[(n - 1, k)]
+                } else {
+                    solved[(i - 1, k)]
+                }
+            })
+        })
+        .collect())
+}
+
+pub(crate) fn sorted_set_union<T: RealField + Copy>(a: &[T], b: &[T]) -> Vec<T> {
     let mut merged = Vec::new();
     let mut ai = 0;
     let mut bi = 0;
@@ -1435,7 +2143,7 @@ fn sorted_set_union<T: RealField + Copy>(a: &[T], b: &[T]) -> Vec<T> {
     merged
 }
 
-fn sorted_set_sub<T: RealField + Copy>(a: &[T], b: &[T]) -> Vec<T> {
+pub(crate) fn sorted_set_sub<T: RealField + Copy>(a: &[T], b: &[T]) -> Vec<T> {
     let mut result = Vec::new();
     let mut ai = 0;
     let mut bi = 0;
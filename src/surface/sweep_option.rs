@@ -0,0 +1,144 @@
+use nalgebra::Vector3;
+
+use crate::misc::{FloatingPoint, Law};
+
+/// Frame to orient the profile along the rail
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum SweepFrame<T> {
+    /// Frenet frames propagated along the rail
+    #[default]
+    Frenet,
+    /// Rotation-minimizing frames computed by the double reflection method
+    RotationMinimizing,
+    /// Frames keeping the profile's up (local y) axis toward the given vector
+    Fixed(Vector3<T>),
+}
+
+/// Law of a value along the rail
+/// The value is evaluated at the normalized position in [0, 1] from the start to the end of the rail.
+pub type SweepLaw<T> = Law<T>;
+
+/// Option for sweeping a profile curve along rails
+#[derive(Debug, Clone, PartialEq)]
+pub struct SweepOption<T> {
+    /// Frame to orient the profile along the rail
+    frame: SweepFrame<T>,
+    /// Scale of the profile along the rail
+    scale: SweepLaw<T>,
+    /// Twist angle (in radians) of the profile around the rail tangent along the rail
+    twist: SweepLaw<T>,
+    /// Degree of the swept surface in the direction of the rail
+    degree_v: Option<usize>,
+    /// Number of the profile sections placed along the rail
+    samples: Option<usize>,
+}
+
+impl<T: FloatingPoint> Default for SweepOption<T> {
+    fn default() -> Self {
+        Self {
+            frame: SweepFrame::default(),
+            scale: SweepLaw::Constant(T::one()),
+            twist: SweepLaw::Constant(T::zero()),
+            degree_v: None,
+            samples: None,
+        }
+    }
+}
+
+impl<T: FloatingPoint> SweepOption<T> {
+    pub fn frame(&self) -> &SweepFrame<T> {
+        &self.frame
+    }
+
+    pub fn scale(&self) -> &SweepLaw<T> {
+        &self.scale
+    }
+
+    pub fn twist(&self) -> &SweepLaw<T> {
+        &self.twist
+    }
+
+    pub fn degree_v(&self) -> Option<usize> {
+        self.degree_v
+    }
+
+    pub fn samples(&self) -> Option<usize> {
+        self.samples
+    }
+
+    pub fn with_frame(mut self, frame: SweepFrame<T>) -> Self {
+        self.frame = frame;
+        self
+    }
+
+    pub fn with_scale<L: Into<SweepLaw<T>>>(mut self, scale: L) -> Self {
+        self.scale = scale.into();
+        self
+    }
+
+    pub fn with_twist<L: Into<SweepLaw<T>>>(mut self, twist: L) -> Self {
+        self.twist = twist.into();
+        self
+    }
+
+    pub fn with_degree_v(mut self, degree_v: Option<usize>) -> Self {
+        self.degree_v = degree_v;
+        self
+    }
+
+    pub fn with_samples(mut self, samples: usize) -> Self {
+        self.samples = Some(samples);
+        self
+    }
+}
+
+/// Option for sweeping a profile curve along two rails
+/// The profile is oriented & scaled by the rails, so no frame, scale or twist laws are available.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Sweep2Option<T> {
+    /// Degree of the swept surface in the direction of the rails
+    degree_v: Option<usize>,
+    /// Number of the profile sections placed along the rails
+    samples: Option<usize>,
+    /// Maximum distance between the ends of the profile and the starts of the rails
+    tolerance: T,
+}
+
+impl<T: FloatingPoint> Default for Sweep2Option<T> {
+    fn default() -> Self {
+        Self {
+            degree_v: None,
+            samples: None,
+            tolerance: T::from_f64(1e-6).unwrap(),
+        }
+    }
+}
+
+impl<T: FloatingPoint> Sweep2Option<T> {
+    pub fn degree_v(&self) -> Option<usize> {
+        self.degree_v
+    }
+
+    pub fn samples(&self) -> Option<usize> {
+        self.samples
+    }
+
+    pub fn tolerance(&self) -> T {
+        self.tolerance
+    }
+
+    pub fn with_degree_v(mut self, degree_v: Option<usize>) -> Self {
+        self.degree_v = degree_v;
+        self
+    }
+
+    pub fn with_samples(mut self, samples: usize) -> Self {
+        self.samples = Some(samples);
+        self
+    }
+
+    pub fn with_tolerance(mut self, tolerance: T) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+}
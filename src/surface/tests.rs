@@ -0,0 +1,160 @@
+use approx::assert_relative_eq;
+use nalgebra::{Point3, Vector3};
+
+use crate::curve::{KnotStyle, NurbsCurve3D};
+
+use super::{NurbsSurface3D, Sweep2Option, UVDirection};
+
+/// Biquadratic height field sampled by the tests of fitting
+fn biquadratic(x: f64, y: f64) -> Point3<f64> {
+    Point3::new(x, y, x * x - x * y * 0.5 + y * y * 0.25)
+}
+
+/// Grid of the biquadratic height field at the integer coordinates
+fn biquadratic_grid(nx: usize, ny: usize) -> Vec<Vec<Point3<f64>>> {
+    (0..nx)
+        .map(|i| (0..ny).map(|j| biquadratic(i as f64, j as f64)).collect())
+        .collect()
+}
+
+/// Check the surface reproduces the biquadratic height field over the grid of the size
+fn assert_reproduces_biquadratic(surface: &NurbsSurface3D<f64>, nx: usize, ny: usize) {
+    let ((u0, u1), (v0, v1)) = surface.knots_domain();
+    for i in 0..=16 {
+        for j in 0..=16 {
+            let (s, t) = (i as f64 / 16., j as f64 / 16.);
+            let p = surface.point_at(u0 + (u1 - u0) * s, v0 + (v1 - v0) * t);
+            let expected = biquadratic(s * (nx - 1) as f64, t * (ny - 1) as f64);
+            assert_relative_eq!(p, expected, epsilon = 1e-8);
+        }
+    }
+}
+
+#[test]
+fn sweep2_matches_rails_by_arc_length() {
+    // parallel rails, the second one runs slowly at the start & fast at the end
+    let rail0 =
+        NurbsCurve3D::<f64>::polyline(&[Point3::new(0., 0., 0.), Point3::new(0., 4., 0.)], false);
+    let rail1 = NurbsCurve3D::bezier(&[
+        Point3::new(2., 0., 0.),
+        Point3::new(2., 0.2, 0.),
+        Point3::new(2., 0.4, 0.),
+        Point3::new(2., 4., 0.),
+    ]);
+    let profile =
+        NurbsCurve3D::polyline(&[Point3::new(0., 0., 0.), Point3::new(2., 0., 0.)], false);
+
+    let swept =
+        NurbsSurface3D::try_sweep2(&profile, &rail0, &rail1, Sweep2Option::default()).unwrap();
+
+    // the sections stay perpendicular to the rails instead of skewing toward the slow rail
+    let (u, v) = swept.knots_domain();
+    for i in 0..=8 {
+        let t = v.0 + (v.1 - v.0) * i as f64 / 8.;
+        let (a, b) = (swept.point_at(u.0, t), swept.point_at(u.1, t));
+        assert_relative_eq!(a.y, b.y, epsilon = 1e-3);
+        assert_relative_eq!(a.x, 0., epsilon = 1e-8);
+        assert_relative_eq!(b.x, 2., epsilon = 1e-8);
+    }
+}
+
+#[test]
+fn interpolate_grid_reproduces_biquadratic() {
+    let grid = biquadratic_grid(5, 4);
+    let surface = NurbsSurface3D::try_interpolate(&grid, 2, 2, KnotStyle::Uniform).unwrap();
+    assert_eq!(surface.control_points().len(), 5);
+    assert_eq!(surface.control_points()[0].len(), 4);
+    assert_reproduces_biquadratic(&surface, 5, 4);
+}
+
+#[test]
+fn approximate_grid_reproduces_biquadratic() {
+    let grid = biquadratic_grid(9, 7);
+    let surface = NurbsSurface3D::try_approximate(&grid, 2, 2, 4, 3, KnotStyle::Uniform).unwrap();
+    assert_eq!(surface.control_points().len(), 4);
+    assert_eq!(surface.control_points()[0].len(), 3);
+    assert_reproduces_biquadratic(&surface, 9, 7);
+}
+
+#[test]
+fn fit_grid_rejects_non_rectangular_grid() {
+    let mut grid = biquadratic_grid(5, 4);
+    grid[2].pop();
+    assert!(NurbsSurface3D::try_interpolate(&grid, 2, 2, KnotStyle::Uniform).is_err());
+    assert!(NurbsSurface3D::try_approximate(&grid, 2, 2, 4, 3, KnotStyle::Uniform).is_err());
+}
+
+#[test]
+fn approximate_scattered_reproduces_plane() {
+    let plane = |x: f64, y: f64| Point3::new(x, y, 1. + x * 0.5 - y * 0.25);
+    let points = (0..200)
+        .map(|i| {
+            let x = ((i * 37) % 101) as f64 / 100. * 4.;
+            let y = ((i * 61) % 103) as f64 / 102. * 3.;
+            plane(x, y)
+        })
+        .collect::<Vec<_>>();
+    let surface = NurbsSurface3D::try_approximate_scattered(&points, 2, 2, 5, 4).unwrap();
+    assert_eq!(surface.control_points().len(), 5);
+    assert_eq!(surface.control_points()[0].len(), 4);
+
+    // the points of the surface stay on the plane
+    let ((u0, u1), (v0, v1)) = surface.knots_domain();
+    for i in 0..=16 {
+        for j in 0..=16 {
+            let u = u0 + (u1 - u0) * i as f64 / 16.;
+            let v = v0 + (v1 - v0) * j as f64 / 16.;
+            let p = surface.point_at(u, v);
+            assert_relative_eq!(p, plane(p.x, p.y), epsilon = 1e-8);
+        }
+    }
+}
+
+#[test]
+fn approximate_scattered_rejects_collinear_points() {
+    let points = (0..50)
+        .map(|i| Point3::new(i as f64, i as f64 * 2., -(i as f64)))
+        .collect::<Vec<_>>();
+    assert!(NurbsSurface3D::try_approximate_scattered(&points, 2, 2, 4, 4).is_err());
+}
+
+#[test]
+fn reduce_elevated_surface_degree() {
+    let circle =
+        NurbsCurve3D::<f64>::try_circle(&Point3::origin(), &Vector3::x(), &Vector3::y(), 1.)
+            .unwrap();
+    let cylinder = NurbsSurface3D::extrude(&circle, &(Vector3::z() * 2.));
+    let tolerance = 1e-6;
+
+    for direction in [UVDirection::U, UVDirection::V] {
+        let degree = match direction {
+            UVDirection::U => cylinder.u_degree(),
+            UVDirection::V => cylinder.v_degree(),
+        };
+        let elevated = cylinder.try_elevate_degree(direction, degree + 2).unwrap();
+        let reduced = elevated
+            .try_reduce_degree(direction, degree, tolerance)
+            .unwrap();
+        assert_eq!(reduced.u_degree(), cylinder.u_degree());
+        assert_eq!(reduced.v_degree(), cylinder.v_degree());
+
+        // the redundant knots introduced by the elevation are removed
+        assert_eq!(
+            reduced.control_points().len(),
+            cylinder.control_points().len()
+        );
+        assert_eq!(
+            reduced.control_points()[0].len(),
+            cylinder.control_points()[0].len()
+        );
+
+        let ((u0, u1), (v0, v1)) = cylinder.knots_domain();
+        for i in 0..=16 {
+            for j in 0..=16 {
+                let u = u0 + (u1 - u0) * i as f64 / 16.;
+                let v = v0 + (v1 - v0) * j as f64 / 16.;
+                assert!((reduced.point_at(u, v) - cylinder.point_at(u, v)).norm() <= tolerance);
+            }
+        }
+    }
+}